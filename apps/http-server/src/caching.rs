@@ -0,0 +1,48 @@
+use axum::http::{HeaderValue, header};
+use axum::response::{IntoResponseParts, ResponseParts};
+use std::convert::Infallible;
+
+/// `Cache-Control` policy for a response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheControl {
+    /// Shared caches (CDNs) may store the response for `max_age` seconds
+    Public { max_age: u32 },
+    /// Only the requesting client may cache; revalidate with the ETag
+    Private,
+    /// Nothing may be cached
+    NoStore,
+}
+
+impl CacheControl {
+    /// Policy for list endpoints: public for `max_age` seconds, or no-store when zero
+    pub fn for_list(max_age: u32) -> Self {
+        match max_age {
+            0 => CacheControl::NoStore,
+            max_age => CacheControl::Public { max_age },
+        }
+    }
+
+    pub fn header_value(&self) -> HeaderValue {
+        match self {
+            CacheControl::Public { max_age } => {
+                HeaderValue::try_from(format!("public, max-age={max_age}"))
+                    .expect("digits are a valid header value")
+            }
+            CacheControl::Private => HeaderValue::from_static("private"),
+            CacheControl::NoStore => HeaderValue::from_static("no-store"),
+        }
+    }
+}
+
+/// Sets `Cache-Control` plus `Vary: Accept-Encoding`, so caches keep the
+/// compressed and plain variants apart
+impl IntoResponseParts for CacheControl {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        let headers = res.headers_mut();
+        headers.insert(header::CACHE_CONTROL, self.header_value());
+        headers.append(header::VARY, HeaderValue::from_static("accept-encoding"));
+        Ok(res)
+    }
+}
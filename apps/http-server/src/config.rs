@@ -1,9 +1,72 @@
+use infrastructure::repositories::PartyRepositoryImpl;
+use shared::Sort;
+use sqlx::postgres::PgSslMode;
+use std::str::FromStr;
 use std::{env, net::SocketAddr};
 
+/// How strictly the database connection must use TLS (`DB_SSL_MODE`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DbSslMode {
+    /// Plaintext only
+    Disable,
+    /// Encrypted, without verifying the server certificate
+    Require,
+    /// Encrypted, with the certificate and host name verified
+    VerifyFull,
+}
+
+impl DbSslMode {
+    pub fn to_pg(self) -> PgSslMode {
+        match self {
+            DbSslMode::Disable => PgSslMode::Disable,
+            DbSslMode::Require => PgSslMode::Require,
+            DbSslMode::VerifyFull => PgSslMode::VerifyFull,
+        }
+    }
+}
+
+impl FromStr for DbSslMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "disable" => Ok(DbSslMode::Disable),
+            "require" => Ok(DbSslMode::Require),
+            "verify-full" => Ok(DbSslMode::VerifyFull),
+            _ => Err(format!(
+                "Invalid DB_SSL_MODE `{s}`: expected disable, require or verify-full"
+            )),
+        }
+    }
+}
+
+/// Item limit for batch endpoints when `MAX_BATCH_SIZE` is unset
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 500;
+
+/// Longest list `search` term, in characters, when `MAX_SEARCH_LENGTH` is unset
+pub const DEFAULT_MAX_SEARCH_LENGTH: usize = 200;
+
 #[derive(Debug, Clone)]
 pub struct Config {
     pub addr: SocketAddr,
     pub db_url: String,
+    /// TLS mode for the database (`DB_SSL_MODE`); `require` outside development,
+    /// and whatever `db_url` says in development when unset
+    pub db_ssl_mode: Option<DbSslMode>,
+    /// Create the database on startup if it is missing (`AUTO_CREATE_DB=true`, dev only)
+    pub auto_create_db: bool,
+    /// Compress responses per `Accept-Encoding` (`COMPRESSION=false` to disable)
+    pub compression: bool,
+    /// Seconds shared caches may keep list responses (`LIST_CACHE_MAX_AGE`, 0 = no-store)
+    pub list_cache_max_age: u32,
+    /// Party list order, e.g. `display_name:asc` (`PARTY_DEFAULT_SORT`, unset = newest first)
+    pub party_default_sort: Option<Sort>,
+    /// Most items a batch or bulk request may carry (`MAX_BATCH_SIZE`)
+    pub max_batch_size: usize,
+    /// Match `search` terms case-sensitively (`SEARCH_CASE_SENSITIVE=true`)
+    pub search_case_sensitive: bool,
+    /// Longest `search` term list endpoints accept, in characters (`MAX_SEARCH_LENGTH`)
+    pub max_search_length: usize,
 }
 
 impl Config {
@@ -22,7 +85,70 @@ impl Config {
 
         let db_url = env::var("DATABASE_URL")?;
 
-        Ok(Self { addr, db_url })
+        let is_dev = matches!(
+            env::var("RUST_ENV").as_deref(),
+            Ok("development" | "dev") | Err(_)
+        );
+        let db_ssl_mode = match env::var("DB_SSL_MODE") {
+            Ok(value) => Some(value.parse::<DbSslMode>()?),
+            Err(_) if is_dev => None,
+            Err(_) => Some(DbSslMode::Require),
+        };
+        let auto_create_db = is_dev && env::var("AUTO_CREATE_DB").is_ok_and(|v| v == "true");
+
+        let compression = env::var("COMPRESSION").map_or(true, |v| v != "false");
+
+        let list_cache_max_age = env::var("LIST_CACHE_MAX_AGE")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(0);
+
+        let party_default_sort = match env::var("PARTY_DEFAULT_SORT") {
+            Ok(value) => Some(
+                Sort::parse(&value, PartyRepositoryImpl::SORTABLE_FIELDS).ok_or_else(|| {
+                    format!(
+                        "Invalid PARTY_DEFAULT_SORT `{value}`: expected <field>[:asc|desc] with field one of {}",
+                        PartyRepositoryImpl::SORTABLE_FIELDS.join(", ")
+                    )
+                })?,
+            ),
+            Err(_) => None,
+        };
+
+        let max_batch_size = limit_from_env("MAX_BATCH_SIZE", DEFAULT_MAX_BATCH_SIZE)?;
+
+        let search_case_sensitive = env::var("SEARCH_CASE_SENSITIVE").is_ok_and(|v| v == "true");
+
+        let max_search_length = limit_from_env("MAX_SEARCH_LENGTH", DEFAULT_MAX_SEARCH_LENGTH)?;
+
+        Ok(Self {
+            addr,
+            db_url,
+            db_ssl_mode,
+            auto_create_db,
+            compression,
+            list_cache_max_age,
+            party_default_sort,
+            max_batch_size,
+            search_case_sensitive,
+            max_search_length,
+        })
+    }
+}
+
+/// Read a positive count from `name`, or `default` when it is unset
+///
+/// A value that is not a positive integer is a startup error rather than a
+/// silent fallback, so a mistyped limit is noticed.
+fn limit_from_env(name: &str, default: usize) -> Result<usize, String> {
+    match env::var(name) {
+        Ok(value) => value
+            .trim()
+            .parse()
+            .ok()
+            .filter(|&limit| limit > 0)
+            .ok_or_else(|| format!("Invalid {name} `{value}`: expected a positive integer")),
+        Err(_) => Ok(default),
     }
 }
 
@@ -31,6 +157,14 @@ impl Default for Config {
         Self {
             addr: "127.0.0.1:3000".parse().unwrap(),
             db_url: String::new(),
+            db_ssl_mode: None,
+            auto_create_db: false,
+            compression: true,
+            list_cache_max_age: 0,
+            party_default_sort: None,
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            search_case_sensitive: false,
+            max_search_length: DEFAULT_MAX_SEARCH_LENGTH,
         }
     }
 }
@@ -0,0 +1,55 @@
+use crate::config::DbSslMode;
+use sqlx::Postgres;
+use sqlx::migrate::{MigrateDatabase, Migrator};
+use sqlx::postgres::{PgConnectOptions, PgPool, PgPoolOptions};
+use std::str::FromStr;
+use tracing::info;
+
+/// Migrations embedded at compile time, shared by startup and health checks
+pub static MIGRATOR: Migrator = sqlx::migrate!("../../migrations");
+
+/// SQLSTATE raised when connecting to a database that does not exist
+const INVALID_CATALOG_NAME: &str = "3D000";
+
+/// Connection options for `db_url`, with `ssl_mode` overriding any `sslmode` in the URL
+pub fn connect_options(
+    db_url: &str,
+    ssl_mode: Option<DbSslMode>,
+) -> Result<PgConnectOptions, sqlx::Error> {
+    let options = PgConnectOptions::from_str(db_url)?;
+    Ok(match ssl_mode {
+        Some(mode) => options.ssl_mode(mode.to_pg()),
+        None => options,
+    })
+}
+
+/// Connect to `db_url` and run pending migrations
+///
+/// With `auto_create`, a missing database is created through the server's
+/// maintenance database before retrying. Meant for local development only.
+pub async fn connect_and_migrate(
+    db_url: &str,
+    ssl_mode: Option<DbSslMode>,
+    auto_create: bool,
+) -> Result<PgPool, sqlx::Error> {
+    let options = PgPoolOptions::new().max_connections(5);
+    let connect_options = connect_options(db_url, ssl_mode)?;
+
+    let pool = match options.clone().connect_with(connect_options.clone()).await {
+        Err(err) if auto_create && is_missing_database(&err) => {
+            info!("Database does not exist, creating it");
+            Postgres::create_database(db_url).await?;
+            options.connect_with(connect_options).await?
+        }
+        result => result?,
+    };
+
+    MIGRATOR.run(&pool).await?;
+    Ok(pool)
+}
+
+fn is_missing_database(err: &sqlx::Error) -> bool {
+    err.as_database_error()
+        .and_then(|db_err| db_err.code())
+        .is_some_and(|code| code == INVALID_CATALOG_NAME)
+}
@@ -1,4 +1,3 @@
 pub mod party;
 
 pub use party::*;
-
@@ -1,9 +1,17 @@
-use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use crate::extractors::Validate;
+use domain::enums::EnumOption;
+use domain::party::{DisplayName, LegalName, Party, PartyType, RegistrationNumber, Tag, Tin};
+use serde::{Deserialize, Deserializer, Serialize, de};
+use serde_json::Value;
+use shared::{DomainError, ErrorResponse, ValidationError};
+use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 /// Party type enum for API
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+///
+/// Serializes as snake_case but deserializes case-insensitively, matching the
+/// domain `PartyType` parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum PartyTypeDto {
     /// Company/Business entity
@@ -13,6 +21,8 @@ pub enum PartyTypeDto {
 }
 
 impl PartyTypeDto {
+    const VARIANTS: &'static [&'static str] = &["company", "person"];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             PartyTypeDto::Company => "company",
@@ -21,9 +31,84 @@ impl PartyTypeDto {
     }
 }
 
+impl<'de> Deserialize<'de> for PartyTypeDto {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        match value.to_lowercase().as_str() {
+            "company" => Ok(PartyTypeDto::Company),
+            "person" => Ok(PartyTypeDto::Person),
+            _ => Err(de::Error::unknown_variant(&value, Self::VARIANTS)),
+        }
+    }
+}
+
+impl From<PartyTypeDto> for PartyType {
+    fn from(dto: PartyTypeDto) -> Self {
+        match dto {
+            PartyTypeDto::Company => PartyType::Company,
+            PartyTypeDto::Person => PartyType::Person,
+        }
+    }
+}
+
+/// Query filters for listing parties
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartyFilterParams {
+    /// Only active (`true`) or inactive (`false`) parties; omit for all
+    #[param(example = true)]
+    pub is_active: Option<bool>,
+
+    /// Search names and TIN by whole word or substring; at most
+    /// `MAX_SEARCH_LENGTH` (default 200) characters, and not only `%`/`_`
+    #[param(example = "acme")]
+    pub search: Option<String>,
+
+    /// Only parties carrying this tag (case-insensitive)
+    #[param(example = "vip")]
+    pub tag: Option<String>,
+
+    /// Order as `field[:asc|desc]`; defaults to relevance when searching,
+    /// otherwise newest first
+    #[param(example = "display_name:asc")]
+    pub sort_by: Option<String>,
+}
+
+/// Query options for fetching a single party
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "kebab-case")]
+pub struct GetPartyParams {
+    /// Also return the party when it has been soft-deleted
+    #[serde(default)]
+    #[param(example = false)]
+    pub include_inactive: bool,
+}
+
+/// Presentation options for endpoints returning parties
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "kebab-case")]
+pub struct PartyViewParams {
+    /// Emit `partyType` as `{ value, label }` instead of the bare value
+    #[serde(default)]
+    #[param(example = false)]
+    pub verbose: bool,
+}
+
+impl PartyViewParams {
+    /// Serialize `party`, labelling enum fields when `verbose` is set
+    pub fn render(&self, party: &Party) -> Value {
+        let mut json = serde_json::to_value(party).expect("Party serializes to JSON");
+        if self.verbose {
+            json["partyType"] = serde_json::to_value(EnumOption::of(&party.party_type()))
+                .expect("EnumOption serializes to JSON");
+        }
+        json
+    }
+}
+
 /// Request to create a new party
 #[derive(Debug, Deserialize, Serialize, ToSchema)]
-#[serde(rename_all = "camelCase")]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CreatePartyRequest {
     /// Party type: 'company' or 'person' (required)
     #[schema(required = true)]
@@ -48,7 +133,7 @@ pub struct CreatePartyRequest {
     #[serde(default)]
     pub legal_name: String,
 
-    /// Tax identification number (optional, MST for Vietnam)
+    /// Tax identification number (optional, companies only; MST for Vietnam)
     #[schema(
         example = "0123456789",
         min_length = 0,
@@ -58,7 +143,7 @@ pub struct CreatePartyRequest {
     #[serde(default)]
     pub tin: String,
 
-    /// Business registration number (optional)
+    /// Business registration number (optional, companies only)
     #[schema(
         example = "BRN-12345",
         min_length = 0,
@@ -69,6 +154,154 @@ pub struct CreatePartyRequest {
     pub registration_number: String,
 }
 
+impl Validate for CreatePartyRequest {
+    fn validate(&self) -> Result<(), ValidationError> {
+        let mut error = ValidationError::new("Invalid request body");
+        let mut check = |field: &str, result: Result<(), DomainError>| {
+            if let Err(err) = result {
+                error.add_field(field, err.message());
+            }
+        };
+
+        // Optional fields are blank when absent; only check what was sent
+        check(
+            "displayName",
+            DisplayName::new(&self.display_name).map(drop),
+        );
+        if !self.legal_name.trim().is_empty() {
+            check("legalName", LegalName::new(&self.legal_name).map(drop));
+        }
+        if !self.tin.trim().is_empty() {
+            check("tin", Tin::new(&self.tin).map(drop));
+        }
+        if !self.registration_number.trim().is_empty() {
+            check(
+                "registrationNumber",
+                RegistrationNumber::new(&self.registration_number).map(drop),
+            );
+        }
+
+        if error.fields.is_empty() {
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+}
+
+/// Field used by first-or-create to find an existing party
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MatchOn {
+    /// Match on tax identification number
+    Tin,
+}
+
+/// Request to return the party matching a key, creating it if none exists
+///
+/// The party is nested rather than flattened: serde ignores
+/// `deny_unknown_fields` on flattened structs, which would let typos through.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FirstOrCreatePartyRequest {
+    /// Which field identifies an existing party
+    #[schema(required = true)]
+    pub match_on: MatchOn,
+
+    /// Party to create when no match is found
+    pub party: CreatePartyRequest,
+}
+
+/// Request to set or clear a party's legal name
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpdateLegalNameRequest {
+    /// New legal name; `null`, omitted or blank clears it
+    #[schema(example = "Acme Corporation Ltd.", max_length = 255)]
+    #[serde(default)]
+    pub legal_name: Option<String>,
+}
+
+/// Request to move a party to another type
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ChangePartyTypeRequest {
+    /// The party's new type; changing to `person` clears `tin` and `registrationNumber`
+    pub party_type: PartyTypeDto,
+}
+
+/// A party after a type change, with the fields the change cleared
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangePartyTypeResponse {
+    pub party: Party,
+    /// Fields that held a value the new type cannot carry; empty when nothing was lost
+    #[schema(example = json!(["tin", "registrationNumber"]))]
+    pub cleared_fields: Vec<String>,
+}
+
+/// Request to set the same fields on many parties
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BulkUpdatePartiesRequest {
+    /// Parties to update; at most `MAX_BATCH_SIZE` IDs
+    pub ids: Vec<Uuid>,
+
+    /// Fields to set; only the listed fields may be bulk-updated
+    pub set: BulkUpdateFields,
+}
+
+/// Fields settable through a bulk update; omitted fields are left as is
+#[derive(Debug, Default, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct BulkUpdateFields {
+    #[schema(example = false)]
+    #[serde(default)]
+    pub is_active: Option<bool>,
+
+    /// Setting `person` also clears `tin` and `registrationNumber`
+    #[serde(default)]
+    pub party_type: Option<PartyTypeDto>,
+}
+
+/// Response after a bulk update
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BulkUpdatePartiesResponse {
+    /// Number of parties changed; unknown or deleted IDs are not counted
+    #[schema(example = 3)]
+    pub affected: u64,
+}
+
+/// Request to attach tags to a party
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AddPartyTagsRequest {
+    /// Tags to add; lowercased, and ones the party already has are ignored
+    #[schema(example = json!(["vip", "prospect"]))]
+    pub tags: Vec<String>,
+}
+
+/// Counts of not deleted parties by active state
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PartyStatusSummaryResponse {
+    #[schema(example = 42)]
+    pub active: u64,
+    #[schema(example = 8)]
+    pub inactive: u64,
+    /// `active + inactive`
+    #[schema(example = 50)]
+    pub total: u64,
+}
+
+/// A party's tags, alphabetically
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PartyTagsResponse {
+    pub tags: Vec<Tag>,
+}
+
 /// Response after successfully creating a party
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -77,3 +310,37 @@ pub struct CreatePartyResponse {
     #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
     pub id: Uuid,
 }
+
+/// Outcome of one item in a batch create, in request order
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchItemResult {
+    /// Position of the item in the request array
+    #[schema(example = 0)]
+    pub index: usize,
+
+    /// HTTP status the item would have received on its own
+    #[schema(example = 201)]
+    pub status: u16,
+
+    /// ID of the created party, when the item succeeded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Uuid>,
+
+    /// Problem details, when the item failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorResponse>,
+}
+
+/// Response for a batch create, returned for every outcome (201, 207 or 400)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCreatePartiesResponse {
+    #[schema(example = 2)]
+    pub succeeded: usize,
+
+    #[schema(example = 1)]
+    pub failed: usize,
+
+    pub results: Vec<BatchItemResult>,
+}
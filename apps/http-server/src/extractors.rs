@@ -0,0 +1,147 @@
+use axum::extract::path::ErrorKind;
+use axum::extract::rejection::{JsonDataError, JsonRejection, PathRejection};
+use axum::extract::{FromRequest, FromRequestParts, Request};
+use axum::http::request::Parts;
+use axum::response::{IntoResponse, Response};
+use serde::de::DeserializeOwned;
+use shared::{AppError, ValidationError};
+
+/// Drop-in replacement for `axum::extract::Path` whose rejection is the
+/// standard RFC 7807 validation error, naming the offending parameter as the field
+#[derive(Debug, Clone, Copy)]
+pub struct Path<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for Path<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match axum::extract::Path::<T>::from_request_parts(parts, state).await {
+            Ok(axum::extract::Path(value)) => Ok(Self(value)),
+            Err(rejection) => Err(path_rejection_to_error(rejection)),
+        }
+    }
+}
+
+fn path_rejection_to_error(rejection: PathRejection) -> AppError {
+    let PathRejection::FailedToDeserializePathParams(err) = rejection else {
+        // Missing params means the route and extractor disagree: a server bug
+        return AppError::Internal(rejection.body_text());
+    };
+
+    let body_text = err.body_text();
+    match err.into_kind() {
+        ErrorKind::ParseErrorAtKey {
+            key,
+            value,
+            expected_type,
+        } => invalid_param(&key, format!("Cannot parse `{value}` as {expected_type}")),
+        ErrorKind::DeserializeError {
+            key,
+            value,
+            message,
+        } => invalid_param(&key, format!("Invalid value `{value}`: {message}")),
+        ErrorKind::InvalidUtf8InPathParam { key } => invalid_param(&key, "Invalid UTF-8"),
+        ErrorKind::WrongNumberOfParameters { .. } | ErrorKind::UnsupportedType { .. } => {
+            AppError::Internal(body_text)
+        }
+        _ => AppError::Validation(ValidationError::new(body_text)),
+    }
+}
+
+fn invalid_param(key: &str, message: impl Into<String>) -> AppError {
+    AppError::Validation(
+        ValidationError::new(format!("Invalid path parameter `{key}`")).with_field(key, message),
+    )
+}
+
+/// JSON request body extractor that reports unknown fields as a 400
+///
+/// Pair with `#[serde(deny_unknown_fields)]` on the body type: a misspelt key
+/// becomes the standard validation error naming the key, instead of a generic
+/// deserialization failure. Other rejections are passed through unchanged.
+#[derive(Debug, Clone, Copy)]
+pub struct JsonBody<T>(pub T);
+
+impl<T, S> FromRequest<S> for JsonBody<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match axum::Json::<T>::from_request(req, state).await {
+            Ok(axum::Json(value)) => Ok(Self(value)),
+            Err(rejection) => Err(json_rejection_to_response(rejection)),
+        }
+    }
+}
+
+fn json_rejection_to_response(rejection: JsonRejection) -> Response {
+    if let JsonRejection::JsonDataError(err) = &rejection
+        && let Some(error) = unknown_field_error(err)
+    {
+        return error.into_response();
+    }
+    rejection.into_response()
+}
+
+/// Request body types that check their own field values
+///
+/// Implemented per DTO; report every invalid field, not just the first, so
+/// clients can fix a form in one round trip.
+pub trait Validate {
+    fn validate(&self) -> Result<(), ValidationError>;
+}
+
+/// JSON request body extractor that also runs the body's [`Validate`] impl
+///
+/// Deserializes like [`JsonBody`], then rejects with the standard validation
+/// error before the handler runs.
+#[derive(Debug, Clone, Copy)]
+pub struct Validated<T>(pub T);
+
+impl<T, S> FromRequest<S> for Validated<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let JsonBody(value) = JsonBody::<T>::from_request(req, state).await?;
+        match value.validate() {
+            Ok(()) => Ok(Self(value)),
+            Err(err) => Err(AppError::Validation(err).into_response()),
+        }
+    }
+}
+
+/// axum's JSON rejection wraps the `serde_path_to_error` error, which carries
+/// the field path separately from serde's message
+///
+/// The field path is structured, but "unknown field" is not: serde only offers
+/// `de::Error::unknown_field`, which formats a message, and serde_json files it
+/// under `Category::Data` with every other type error. The message prefix is
+/// therefore the only way to tell it apart; the API tests pin its format.
+fn unknown_field_error(err: &JsonDataError) -> Option<AppError> {
+    let source = std::iter::successors(std::error::Error::source(err), |e| e.source())
+        .find_map(|e| e.downcast_ref::<serde_path_to_error::Error<serde_json::Error>>())?;
+    let path = source.path().to_string();
+    // serde's `unknown_field` message, minus serde_json's trailing position
+    let message = source.inner().to_string();
+    let message = message
+        .rsplit_once(" at line")
+        .map_or(message.as_str(), |(m, _)| m);
+    if !message.starts_with("unknown field") {
+        return None;
+    }
+
+    Some(AppError::Validation(
+        ValidationError::new(format!("Unknown field `{path}`")).with_field(path, message),
+    ))
+}
@@ -0,0 +1,16 @@
+use axum::{Json, response::IntoResponse};
+use domain::enums::{EnumDescriptor, registry};
+use shared::{SuccessResponse, success};
+
+/// List every domain enum with its values and labels
+#[utoipa::path(
+    get,
+    path = "/api/enums",
+    responses(
+        (status = 200, description = "Successfully retrieved enum options", body = inline(SuccessResponse<Vec<EnumDescriptor>>))
+    ),
+    tag = "Enums"
+)]
+pub async fn list_enums() -> impl IntoResponse {
+    Json(success(registry()))
+}
@@ -0,0 +1,16 @@
+use axum::{Json, response::IntoResponse};
+use shared::error::error_catalog;
+use shared::{ErrorCodeInfo, SuccessResponse, success};
+
+/// List every error code the API can return, with its status and meaning
+#[utoipa::path(
+    get,
+    path = "/api/errors",
+    responses(
+        (status = 200, description = "Successfully retrieved the error catalog", body = inline(SuccessResponse<Vec<ErrorCodeInfo>>))
+    ),
+    tag = "Errors"
+)]
+pub async fn list_error_codes() -> impl IntoResponse {
+    Json(success(error_catalog::ALL))
+}
@@ -0,0 +1,85 @@
+use crate::app_state::AppState;
+use crate::db::MIGRATOR;
+use axum::{Json, extract::Query, extract::State, response::IntoResponse};
+use serde::{Deserialize, Serialize};
+use shared::{AppError, SuccessResponse, success};
+use sqlx::PgPool;
+use std::collections::HashSet;
+use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
+
+/// Query parameters for the readiness probe
+#[derive(Debug, Default, Deserialize, IntoParams)]
+pub struct ReadyParams {
+    /// Also verify every embedded migration has been applied
+    #[serde(default)]
+    #[param(example = false)]
+    pub deep: bool,
+}
+
+/// Readiness probe result
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReadyResponse {
+    #[schema(example = "ready")]
+    pub status: &'static str,
+}
+
+/// Readiness probe
+///
+/// Checks database connectivity; with `deep=true` also confirms the database
+/// schema is at the latest embedded migration.
+#[utoipa::path(
+    get,
+    path = "/ready",
+    params(ReadyParams),
+    responses(
+        (status = 200, description = "Service is ready", body = inline(SuccessResponse<ReadyResponse>)),
+        (
+            status = 503,
+            description = "Database unreachable or behind the embedded migrations",
+            body = inline(shared::ErrorResponse)
+        )
+    ),
+    tag = "Health"
+)]
+pub async fn ready(
+    Query(params): Query<ReadyParams>,
+    State(app_state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, AppError> {
+    sqlx::query("SELECT 1")
+        .execute(&app_state.pool)
+        .await
+        .map_err(|_| AppError::ServiceUnavailable("Database is unreachable".to_string()))?;
+
+    if params.deep {
+        ensure_migrations_applied(&app_state.pool).await?;
+    }
+
+    Ok(Json(success(ReadyResponse { status: "ready" })))
+}
+
+/// Fail with 503 unless every embedded up-migration is recorded as applied
+async fn ensure_migrations_applied(pool: &PgPool) -> Result<(), AppError> {
+    let applied: HashSet<i64> =
+        sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success")
+            .fetch_all(pool)
+            .await
+            .map_err(|_| {
+                AppError::ServiceUnavailable("Database has not been migrated".to_string())
+            })?
+            .into_iter()
+            .collect();
+
+    let pending = MIGRATOR
+        .iter()
+        .filter(|m| m.migration_type.is_up_migration() && !applied.contains(&m.version))
+        .count();
+
+    if pending > 0 {
+        return Err(AppError::ServiceUnavailable(format!(
+            "Database is behind by {pending} migration(s)"
+        )));
+    }
+
+    Ok(())
+}
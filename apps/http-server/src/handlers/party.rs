@@ -1,39 +1,255 @@
 use crate::app_state::AppState;
-use crate::dto::{CreatePartyRequest, CreatePartyResponse};
-use application::party::{CreatePartyUseCase, GetPartyUseCase, ListPartiesUseCase};
-use axum::{Json, extract::Path, extract::Query, extract::State, response::IntoResponse};
-use domain::party::Party;
+use crate::caching::CacheControl;
+use crate::dto::{
+    AddPartyTagsRequest, BatchCreatePartiesResponse, BatchItemResult, BulkUpdatePartiesRequest,
+    BulkUpdatePartiesResponse, ChangePartyTypeRequest, ChangePartyTypeResponse, CreatePartyRequest,
+    CreatePartyResponse, FirstOrCreatePartyRequest, GetPartyParams, MatchOn, PartyFilterParams,
+    PartyStatusSummaryResponse, PartyTagsResponse, PartyViewParams, UpdateLegalNameRequest,
+};
+use crate::extractors::{JsonBody, Path, Validated};
+use crate::middleware::feature_flags::{Flags, RETURN_FULL_ENTITY};
+use crate::middleware::transaction::Tx;
+use crate::prefer::{Prefer, ReturnPreference};
+use application::party::{
+    AddPartyTagsUseCase, BulkUpdatePartiesUseCase, ChangePartyTypeUseCase, CreatePartyInput,
+    CreatePartyUseCase, ExportPartiesUseCase, FirstOrCreateOutcome, FirstOrCreatePartyUseCase,
+    GetPartyStatusSummaryUseCase, GetPartyUseCase, ListPartiesUseCase, RemovePartyTagUseCase,
+    UpdatePartyLegalNameUseCase,
+};
+use application::ports::{PartyChanges, PartyFilter};
+use axum::body::Body;
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::{
+    Json,
+    extract::Query,
+    extract::State,
+    response::{IntoResponse, Response},
+};
+use domain::party::{Party, Tag};
+use futures_util::StreamExt;
+use futures_util::stream;
 use infrastructure::repositories::PartyRepositoryImpl;
-use shared::{AppError, PageParams, SuccessResponse, created, success, success_with_pagination};
+use shared::{
+    AppError, PageParams, Sort, SuccessResponse, ValidationError, content_etag,
+    created_at_location, if_none_match, no_content, success, success_with_pagination,
+};
+use sqlx::Acquire;
 use std::sync::Arc;
 use uuid::Uuid;
 
+/// Page size applied to the party list when the client omits `page-size`
+const PARTY_DEFAULT_PAGE_SIZE: u32 = 20;
+const PARTY_MAX_PAGE_SIZE: u32 = 100;
+/// Serialized lines buffered between the database cursor and the response body
+const PARTY_EXPORT_BUFFER: usize = 64;
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+impl From<CreatePartyRequest> for CreatePartyInput {
+    fn from(request: CreatePartyRequest) -> Self {
+        Self {
+            party_type: request.party_type.as_str().to_string(),
+            display_name: request.display_name,
+            legal_name: request.legal_name,
+            tin: request.tin,
+            registration_number: request.registration_number,
+        }
+    }
+}
+
 /// List parties with pagination
 #[utoipa::path(
     get,
     path = "/list",
-    params(PageParams),
+    params(PageParams, PartyFilterParams, PartyViewParams),
     responses(
-        (status = 200, description = "Successfully retrieved parties", body = inline(SuccessResponse<Vec<Party>>)),
-        (status = 400, description = "Invalid pagination parameters"),
+        (
+            status = 200,
+            description = "Successfully retrieved parties",
+            body = inline(SuccessResponse<Vec<Party>>),
+            headers(
+                ("Cache-Control" = String, description = "public, max-age=N when list caching is configured, otherwise no-store"),
+                ("Vary" = String, description = "Accept-Encoding")
+            )
+        ),
+        (status = 400, description = "Invalid pagination or filter parameters"),
         (status = 500, description = "Internal server error")
     ),
     tag = "Parties"
 )]
 pub async fn list_parties(
     Query(params): Query<PageParams>,
+    Query(filter): Query<PartyFilterParams>,
+    Query(view): Query<PartyViewParams>,
     State(app_state): State<Arc<AppState>>,
 ) -> Result<impl IntoResponse, AppError> {
-    let params = params.validate(100);
+    let params = params.with_default(PARTY_DEFAULT_PAGE_SIZE, PARTY_MAX_PAGE_SIZE);
+    let filter = PartyFilter {
+        is_active: filter.is_active,
+        search: filter
+            .search
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .map(|s| check_search(s, app_state.config.max_search_length))
+            .transpose()?,
+        tag: filter
+            .tag
+            .map(Tag::new)
+            .transpose()
+            .map_err(|err| ValidationError::for_field("tag", err))?,
+        sort: filter.sort_by.map(|value| parse_sort(&value)).transpose()?,
+    };
+
+    let repository = app_state
+        .config
+        .party_default_sort
+        .map_or_else(
+            PartyRepositoryImpl::new,
+            PartyRepositoryImpl::with_default_sort,
+        )
+        .with_case_sensitive_search(app_state.config.search_case_sensitive);
 
-    let (parties, pagination) = ListPartiesUseCase::new(PartyRepositoryImpl::new())
-        .execute(&app_state.pool, params.page, params.page_size)
+    let result = ListPartiesUseCase::new(repository)
+        .execute(&app_state.pool, params.pagination(), filter)
         .await?;
 
-    Ok(Json(success_with_pagination(parties, pagination)))
+    Ok((
+        CacheControl::for_list(app_state.config.list_cache_max_age),
+        Json(success_with_pagination(
+            result
+                .items
+                .iter()
+                .map(|p| view.render(p))
+                .collect::<Vec<_>>(),
+            result.pagination,
+        )),
+    ))
+}
+
+/// Count parties by active state
+///
+/// Unpaginated totals for status widgets; soft-deleted parties are not counted.
+#[utoipa::path(
+    get,
+    path = "/status-summary",
+    responses(
+        (
+            status = 200,
+            description = "Active, inactive and total party counts",
+            body = inline(SuccessResponse<PartyStatusSummaryResponse>)
+        ),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Parties"
+)]
+pub async fn get_party_status_summary(
+    State(app_state): State<Arc<AppState>>,
+) -> Result<Json<SuccessResponse<PartyStatusSummaryResponse>>, AppError> {
+    let counts = GetPartyStatusSummaryUseCase::new(PartyRepositoryImpl::new())
+        .execute(&app_state.pool)
+        .await?;
+
+    Ok(Json(success(PartyStatusSummaryResponse {
+        active: counts.active,
+        inactive: counts.inactive,
+        total: counts.total(),
+    })))
+}
+
+/// Export all parties as newline-delimited JSON
+///
+/// Streams one party object per line, oldest first, straight from a database
+/// cursor so the table is never held in memory. A database error after the
+/// first line aborts the body, leaving the client with a truncated download
+/// rather than a silently incomplete one.
+#[utoipa::path(
+    get,
+    path = "/export.ndjson",
+    responses(
+        (
+            status = 200,
+            description = "One JSON party per line",
+            body = Party,
+            content_type = "application/x-ndjson"
+        ),
+        (status = 500, description = "Internal server error")
+    ),
+    tag = "Parties"
+)]
+pub async fn export_parties_ndjson(State(app_state): State<Arc<AppState>>) -> Response {
+    let (tx, rx) = tokio::sync::mpsc::channel::<Result<String, AppError>>(PARTY_EXPORT_BUFFER);
+    let pool = app_state.pool.clone();
+
+    tokio::spawn(async move {
+        let use_case = ExportPartiesUseCase::new(PartyRepositoryImpl::new());
+        let mut parties = use_case.execute(&pool);
+
+        while let Some(party) = parties.next().await {
+            let line = party.and_then(|party| {
+                let mut line = serde_json::to_string(&party)
+                    .map_err(|err| AppError::Internal(err.to_string()))?;
+                line.push('\n');
+                Ok(line)
+            });
+            let failed = line.is_err();
+            if let Err(err) = &line {
+                tracing::error!(error = %err, "party export aborted");
+            }
+            // A closed channel means the client went away; stop reading rows
+            if tx.send(line).await.is_err() || failed {
+                break;
+            }
+        }
+    });
+
+    let body = stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|line| (line, rx))
+    });
+
+    (
+        [(header::CONTENT_TYPE, NDJSON_CONTENT_TYPE)],
+        Body::from_stream(body),
+    )
+        .into_response()
+}
+
+/// Reject `search` terms that are too long or made only of LIKE wildcards
+///
+/// Wildcards are matched literally, so `%` or `__` would only find names that
+/// contain them; such terms are almost always a client trying `*`-style search.
+fn check_search(search: String, max_length: usize) -> Result<String, ValidationError> {
+    let invalid =
+        |detail: String| ValidationError::new("Invalid search term").with_field("search", detail);
+
+    if search.chars().count() > max_length {
+        return Err(invalid(format!("At most {max_length} characters")));
+    }
+    if search.chars().all(|c| matches!(c, '%' | '_')) {
+        return Err(invalid(
+            "Must contain something other than the wildcards `%` and `_`".to_string(),
+        ));
+    }
+    Ok(search)
+}
+
+/// Parse a `sort-by` value against the party repository's sortable columns
+fn parse_sort(value: &str) -> Result<Sort, ValidationError> {
+    Sort::parse(value, PartyRepositoryImpl::SORTABLE_FIELDS).ok_or_else(|| {
+        ValidationError::new(format!("Invalid sort `{value}`")).with_field(
+            "sort-by",
+            format!(
+                "Expected <field>[:asc|desc] with field one of {}",
+                PartyRepositoryImpl::SORTABLE_FIELDS.join(", ")
+            ),
+        )
+    })
 }
 
 /// Create a new party
+///
+/// Returns `{ id }` by default. `Prefer: return=representation` (or the
+/// `return_full_entity` feature flag) returns the full party instead, and
+/// `Prefer: return=minimal` forces `{ id }`; a stated preference is echoed in
+/// `Preference-Applied`.
 #[utoipa::path(
     post,
     path = "/create",
@@ -45,8 +261,12 @@ pub async fn list_parties(
     responses(
         (
             status = 201,
-            description = "Party created successfully",
-            body = inline(SuccessResponse<CreatePartyResponse>)
+            description = "Party created successfully; the full party for `Prefer: return=representation` or the `return_full_entity` feature flag",
+            body = inline(SuccessResponse<CreatePartyResponse>),
+            headers(
+                ("Location" = String, description = "URL of the created party"),
+                ("Preference-Applied" = String, description = "The honoured `return` preference, when one was sent")
+            )
         ),
         (
             status = 400,
@@ -68,21 +288,261 @@ pub async fn list_parties(
 )]
 pub async fn create_party(
     State(app_state): State<Arc<AppState>>,
-    Json(request): Json<CreatePartyRequest>,
+    flags: Flags,
+    prefer: Prefer,
+    Validated(request): Validated<CreatePartyRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let input = application::party::CreatePartyInput {
-        party_type: request.party_type.as_str().to_string(),
-        display_name: request.display_name,
-        legal_name: request.legal_name,
-        tin: request.tin,
-        registration_number: request.registration_number,
+    let party = CreatePartyUseCase::new(PartyRepositoryImpl::new())
+        .execute(&app_state.pool, request.into())
+        .await?;
+
+    let location = format!("/api/parties/get/{}", party.id());
+    let default = if flags.is_enabled(RETURN_FULL_ENTITY) {
+        ReturnPreference::Representation
+    } else {
+        ReturnPreference::Minimal
+    };
+    let response = match prefer.return_or(default) {
+        ReturnPreference::Representation => created_at_location(party, location),
+        ReturnPreference::Minimal => {
+            created_at_location(CreatePartyResponse { id: party.id() }, location)
+        }
     };
 
-    let party = CreatePartyUseCase::new(PartyRepositoryImpl::new())
-        .execute(&app_state.pool, input)
+    Ok((prefer.applied(), response))
+}
+
+/// Create several parties in one request
+///
+/// Each item runs in its own savepoint, so one bad item does not undo the
+/// others. The status reflects the whole batch: 201 when every item was
+/// created, 400 when none were, and 207 for a mix. The body always lists the
+/// per-item outcome in request order.
+#[utoipa::path(
+    post,
+    path = "/batch",
+    request_body(
+        content = Vec<CreatePartyRequest>,
+        description = "Parties to create",
+        content_type = "application/json"
+    ),
+    responses(
+        (
+            status = 201,
+            description = "All parties created",
+            body = inline(SuccessResponse<BatchCreatePartiesResponse>)
+        ),
+        (
+            status = 207,
+            description = "Some parties created; see per-item results",
+            body = inline(SuccessResponse<BatchCreatePartiesResponse>)
+        ),
+        (
+            status = 400,
+            description = "No parties created, or the batch itself is invalid",
+            body = inline(SuccessResponse<BatchCreatePartiesResponse>)
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = inline(shared::ErrorResponse)
+        )
+    ),
+    tag = "Parties"
+)]
+pub async fn batch_create_parties(
+    State(app_state): State<Arc<AppState>>,
+    mut tx: Tx,
+    JsonBody(requests): JsonBody<Vec<CreatePartyRequest>>,
+) -> Result<impl IntoResponse, AppError> {
+    let max = app_state.config.max_batch_size;
+    if requests.is_empty() || requests.len() > max {
+        return Err(AppError::Validation(ValidationError::new(format!(
+            "Batch must contain between 1 and {max} parties, got {}",
+            requests.len()
+        ))));
+    }
+
+    let use_case = CreatePartyUseCase::new(PartyRepositoryImpl::new());
+    let mut results = Vec::with_capacity(requests.len());
+
+    for (index, request) in requests.into_iter().enumerate() {
+        let mut savepoint = tx.begin().await?;
+        let result = match use_case.execute(&mut *savepoint, request.into()).await {
+            Ok(party) => {
+                savepoint.commit().await?;
+                BatchItemResult {
+                    index,
+                    status: StatusCode::CREATED.as_u16(),
+                    id: Some(party.id()),
+                    error: None,
+                }
+            }
+            Err(err) => {
+                savepoint.rollback().await?;
+                let error = err.to_error_response();
+                BatchItemResult {
+                    index,
+                    status: error.status,
+                    id: None,
+                    error: Some(error),
+                }
+            }
+        };
+        results.push(result);
+    }
+
+    let succeeded = results.iter().filter(|r| r.id.is_some()).count();
+    let failed = results.len() - succeeded;
+    let status = match (succeeded, failed) {
+        (_, 0) => StatusCode::CREATED,
+        (0, _) => StatusCode::BAD_REQUEST,
+        _ => StatusCode::MULTI_STATUS,
+    };
+
+    Ok((
+        status,
+        Json(success(BatchCreatePartiesResponse {
+            succeeded,
+            failed,
+            results,
+        })),
+    ))
+}
+
+/// Set the same fields on many parties with a single UPDATE
+///
+/// Only `isActive` and `partyType` can be set; any other key in `set` is
+/// rejected. Unknown and deleted IDs are skipped and not counted.
+#[utoipa::path(
+    post,
+    path = "/bulk-update",
+    request_body(
+        content = BulkUpdatePartiesRequest,
+        description = "Party IDs and the fields to set on them",
+        content_type = "application/json"
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Parties updated",
+            body = inline(SuccessResponse<BulkUpdatePartiesResponse>)
+        ),
+        (
+            status = 400,
+            description = "Empty or oversized ID list, or an unknown or missing field",
+            body = inline(shared::ErrorResponse)
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = inline(shared::ErrorResponse)
+        )
+    ),
+    tag = "Parties"
+)]
+pub async fn bulk_update_parties(
+    State(app_state): State<Arc<AppState>>,
+    mut tx: Tx,
+    JsonBody(request): JsonBody<BulkUpdatePartiesRequest>,
+) -> Result<Json<SuccessResponse<BulkUpdatePartiesResponse>>, AppError> {
+    let max = app_state.config.max_batch_size;
+    if request.ids.len() > max {
+        return Err(AppError::Validation(
+            ValidationError::new(format!(
+                "Bulk update is limited to {max} parties, got {}",
+                request.ids.len()
+            ))
+            .with_field("ids", format!("At most {max} IDs")),
+        ));
+    }
+
+    let changes = PartyChanges {
+        is_active: request.set.is_active,
+        party_type: request.set.party_type.map(Into::into),
+    };
+    let affected = BulkUpdatePartiesUseCase::new(PartyRepositoryImpl::new())
+        .execute(&mut *tx, request.ids, changes)
         .await?;
 
-    Ok(created(CreatePartyResponse { id: party.id() }))
+    Ok(Json(success(BulkUpdatePartiesResponse { affected })))
+}
+
+/// Header telling first-or-create callers which branch was taken
+const FIRST_OR_CREATE_OUTCOME_HEADER: &str = "x-first-or-create";
+
+/// Return the party matching `matchOn`, creating it if none exists
+///
+/// Responds 200 with the existing party or 201 with the new one; the
+/// `X-First-Or-Create` header is `found` or `created` accordingly.
+#[utoipa::path(
+    post,
+    path = "/first-or-create",
+    request_body(
+        content = FirstOrCreatePartyRequest,
+        description = "Match key and the party to create when nothing matches",
+        content_type = "application/json"
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Existing party matched",
+            body = inline(SuccessResponse<Party>),
+            headers(
+                ("X-First-Or-Create" = String, description = "found")
+            )
+        ),
+        (
+            status = 201,
+            description = "No match; party created",
+            body = inline(SuccessResponse<Party>),
+            headers(
+                ("X-First-Or-Create" = String, description = "created"),
+                ("Location" = String, description = "URL of the created party")
+            )
+        ),
+        (
+            status = 400,
+            description = "Invalid request data - validation failed",
+            body = inline(shared::ErrorResponse)
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = inline(shared::ErrorResponse)
+        )
+    ),
+    tag = "Parties"
+)]
+pub async fn first_or_create_party(
+    mut tx: Tx,
+    JsonBody(request): JsonBody<FirstOrCreatePartyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let (party, outcome) = match request.match_on {
+        MatchOn::Tin => {
+            FirstOrCreatePartyUseCase::new(PartyRepositoryImpl::new())
+                .execute(&mut *tx, request.party.into())
+                .await?
+        }
+    };
+
+    let response = match outcome {
+        FirstOrCreateOutcome::Found => (
+            [(FIRST_OR_CREATE_OUTCOME_HEADER, "found")],
+            Json(success(party)),
+        )
+            .into_response(),
+        FirstOrCreateOutcome::Created => {
+            let location = format!("/api/parties/get/{}", party.id());
+            (
+                [(FIRST_OR_CREATE_OUTCOME_HEADER, "created")],
+                created_at_location(party, location),
+            )
+                .into_response()
+        }
+    };
+
+    Ok(response)
 }
 
 /// Get a single party by ID
@@ -90,17 +550,31 @@ pub async fn create_party(
     get,
     path = "/get/{id}",
     params(
-        ("id" = Uuid, Path, description = "Party unique identifier")
+        ("id" = Uuid, Path, description = "Party unique identifier"),
+        GetPartyParams,
+        PartyViewParams
     ),
     responses(
         (
             status = 200,
             description = "Successfully retrieved party",
-            body = inline(SuccessResponse<Party>)
+            body = inline(SuccessResponse<Party>),
+            headers(
+                ("Cache-Control" = String, description = "private"),
+                ("ETag" = String, description = "Hash of the returned representation")
+            )
+        ),
+        (
+            status = 304,
+            description = "Not modified: `If-None-Match` names the current ETag",
+            headers(
+                ("Cache-Control" = String, description = "private"),
+                ("ETag" = String, description = "Hash of the current representation")
+            )
         ),
         (
             status = 404,
-            description = "Party not found",
+            description = "Party not found, or deleted without include-inactive",
             body = inline(shared::ErrorResponse)
         ),
         (
@@ -119,10 +593,237 @@ pub async fn create_party(
 pub async fn get_party(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<Uuid>,
-) -> Result<impl IntoResponse, AppError> {
+    Query(params): Query<GetPartyParams>,
+    Query(view): Query<PartyViewParams>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
     let party = GetPartyUseCase::new(PartyRepositoryImpl::new())
-        .execute(&app_state.pool, id)
+        .execute(&app_state.pool, id, params.include_inactive)
+        .await?;
+
+    // Hash what is sent, so plain and verbose views get distinct tags
+    let body = view.render(&party);
+    let etag = content_etag(&body);
+    if if_none_match(&headers, &etag) {
+        return Ok((
+            StatusCode::NOT_MODIFIED,
+            CacheControl::Private,
+            [(header::ETAG, etag)],
+        )
+            .into_response());
+    }
+
+    Ok((
+        CacheControl::Private,
+        [(header::ETAG, etag)],
+        Json(success(body)),
+    )
+        .into_response())
+}
+
+/// Set or clear a party's legal name
+///
+/// Returns the updated party, or just `{ id }` for `Prefer: return=minimal`.
+#[utoipa::path(
+    put,
+    path = "/{id}/legal-name",
+    params(
+        ("id" = Uuid, Path, description = "Party unique identifier")
+    ),
+    request_body(
+        content = UpdateLegalNameRequest,
+        description = "New legal name, or null to clear it",
+        content_type = "application/json"
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Legal name updated",
+            body = inline(SuccessResponse<Party>),
+            headers(
+                ("Preference-Applied" = String, description = "The honoured `return` preference, when one was sent")
+            )
+        ),
+        (
+            status = 400,
+            description = "Invalid legal name or UUID",
+            body = inline(shared::ErrorResponse)
+        ),
+        (
+            status = 404,
+            description = "Party not found",
+            body = inline(shared::ErrorResponse)
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = inline(shared::ErrorResponse)
+        )
+    ),
+    tag = "Parties"
+)]
+pub async fn update_party_legal_name(
+    mut tx: Tx,
+    prefer: Prefer,
+    Path(id): Path<Uuid>,
+    JsonBody(request): JsonBody<UpdateLegalNameRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let party = UpdatePartyLegalNameUseCase::new(PartyRepositoryImpl::new())
+        .execute(&mut *tx, id, request.legal_name)
+        .await?;
+
+    let response = match prefer.return_or(ReturnPreference::Representation) {
+        ReturnPreference::Representation => Json(success(party)).into_response(),
+        ReturnPreference::Minimal => {
+            Json(success(CreatePartyResponse { id: party.id() })).into_response()
+        }
+    };
+
+    Ok((prefer.applied(), response))
+}
+
+/// Change a party's type
+///
+/// Moving a company to `person` clears its TIN and registration number; the
+/// cleared fields are listed in `clearedFields` so the client can warn about
+/// the lost data. Changing to the current type changes nothing.
+#[utoipa::path(
+    put,
+    path = "/{id}/type",
+    params(
+        ("id" = Uuid, Path, description = "Party unique identifier")
+    ),
+    request_body(
+        content = ChangePartyTypeRequest,
+        description = "The party's new type",
+        content_type = "application/json"
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Type changed",
+            body = inline(SuccessResponse<ChangePartyTypeResponse>)
+        ),
+        (
+            status = 400,
+            description = "Unknown party type or invalid UUID",
+            body = inline(shared::ErrorResponse)
+        ),
+        (
+            status = 404,
+            description = "Party not found",
+            body = inline(shared::ErrorResponse)
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = inline(shared::ErrorResponse)
+        )
+    ),
+    tag = "Parties"
+)]
+pub async fn change_party_type(
+    mut tx: Tx,
+    Path(id): Path<Uuid>,
+    JsonBody(request): JsonBody<ChangePartyTypeRequest>,
+) -> Result<Json<SuccessResponse<ChangePartyTypeResponse>>, AppError> {
+    let change = ChangePartyTypeUseCase::new(PartyRepositoryImpl::new())
+        .execute(&mut *tx, id, request.party_type.into())
+        .await?;
+
+    Ok(Json(success(ChangePartyTypeResponse {
+        party: change.party,
+        cleared_fields: change
+            .cleared_fields
+            .into_iter()
+            .map(String::from)
+            .collect(),
+    })))
+}
+
+/// Attach tags to a party
+#[utoipa::path(
+    post,
+    path = "/{id}/tags",
+    params(
+        ("id" = Uuid, Path, description = "Party unique identifier")
+    ),
+    request_body(
+        content = AddPartyTagsRequest,
+        description = "Tags to add",
+        content_type = "application/json"
+    ),
+    responses(
+        (
+            status = 200,
+            description = "Tags added; every tag the party now has",
+            body = inline(SuccessResponse<PartyTagsResponse>)
+        ),
+        (
+            status = 400,
+            description = "Empty or invalid tags, or invalid UUID",
+            body = inline(shared::ErrorResponse)
+        ),
+        (
+            status = 404,
+            description = "Party not found",
+            body = inline(shared::ErrorResponse)
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = inline(shared::ErrorResponse)
+        )
+    ),
+    tag = "Parties"
+)]
+pub async fn add_party_tags(
+    mut tx: Tx,
+    Path(id): Path<Uuid>,
+    JsonBody(request): JsonBody<AddPartyTagsRequest>,
+) -> Result<Json<SuccessResponse<PartyTagsResponse>>, AppError> {
+    let tags = AddPartyTagsUseCase::new(PartyRepositoryImpl::new())
+        .execute(&mut *tx, id, request.tags)
+        .await?;
+
+    Ok(Json(success(PartyTagsResponse { tags })))
+}
+
+/// Detach a tag from a party
+#[utoipa::path(
+    delete,
+    path = "/{id}/tags/{tag}",
+    params(
+        ("id" = Uuid, Path, description = "Party unique identifier"),
+        ("tag" = String, Path, description = "Tag to remove (case-insensitive)")
+    ),
+    responses(
+        (status = 204, description = "Tag removed, or the party did not have it"),
+        (
+            status = 400,
+            description = "Invalid tag or UUID",
+            body = inline(shared::ErrorResponse)
+        ),
+        (
+            status = 404,
+            description = "Party not found",
+            body = inline(shared::ErrorResponse)
+        ),
+        (
+            status = 500,
+            description = "Internal server error",
+            body = inline(shared::ErrorResponse)
+        )
+    ),
+    tag = "Parties"
+)]
+pub async fn remove_party_tag(
+    mut tx: Tx,
+    Path((id, tag)): Path<(Uuid, String)>,
+) -> Result<impl IntoResponse, AppError> {
+    RemovePartyTagUseCase::new(PartyRepositoryImpl::new())
+        .execute(&mut *tx, id, tag)
         .await?;
 
-    Ok(Json(success(party)))
+    Ok(no_content())
 }
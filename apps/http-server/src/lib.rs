@@ -1,7 +1,21 @@
 pub mod app_state;
+pub mod caching;
 pub mod config;
+pub mod db;
 pub mod dto;
+pub mod extractors;
 pub mod handlers {
+    pub mod enums;
+    pub mod errors;
+    pub mod health;
     pub mod party;
 }
+pub mod middleware {
+    pub mod compression;
+    pub mod feature_flags;
+    pub mod locale;
+    pub mod trailing_slash;
+    pub mod transaction;
+}
+pub mod prefer;
 pub mod routes;
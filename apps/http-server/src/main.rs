@@ -1,7 +1,13 @@
 use std::{env, fs, sync::Arc};
 
-use http_server::{app_state::AppState, config::Config, routes};
-use sqlx::postgres::PgPoolOptions;
+use axum::{ServiceExt, extract::Request};
+use http_server::{
+    app_state::AppState,
+    config::Config,
+    db,
+    middleware::{compression::compression_layer, trailing_slash::trim_trailing_slash},
+    routes,
+};
 use tower_http::{LatencyUnit, cors::CorsLayer, trace::TraceLayer};
 use tracing::{Level, info};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -25,15 +31,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Starting VPB ERP Backend...");
 
     // Initialize database pool with migrations
-    let pool = PgPoolOptions::new()
-        .max_connections(5)
-        .connect(&config.db_url)
-        .await?;
-
-    sqlx::migrate!("../../migrations").run(&pool).await?;
+    let pool =
+        db::connect_and_migrate(&config.db_url, config.db_ssl_mode, config.auto_create_db).await?;
     info!("✅ Database migrations completed");
 
-    let app_state = Arc::new(AppState { pool });
+    let app_state = Arc::new(AppState {
+        pool,
+        config: config.clone(),
+    });
 
     // Build application with routes and OpenAPI docs
     let (app, openapi) = OpenApiRouter::with_openapi(ApiDoc::openapi())
@@ -63,10 +68,19 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 ),
         );
 
+    let app = if config.compression {
+        app.layer(compression_layer())
+    } else {
+        app
+    };
+
+    // Wraps the router rather than layering on it so it runs before routing
+    let app = trim_trailing_slash(app);
+
     let listener = tokio::net::TcpListener::bind(config.addr).await?;
     info!("🚀 Listening on http://{}", config.addr);
 
-    axum::serve(listener, app)
+    axum::serve(listener, ServiceExt::<Request>::into_make_service(app))
         .with_graceful_shutdown(shutdown_signal())
         .await?;
 
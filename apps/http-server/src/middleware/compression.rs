@@ -0,0 +1,20 @@
+use tower_http::compression::{
+    CompressionLayer,
+    predicate::{NotForContentType, Predicate, SizeAbove},
+};
+
+/// Responses smaller than this are sent uncompressed
+pub const COMPRESSION_MIN_SIZE: u16 = 1024;
+
+/// Response compression negotiated from `Accept-Encoding` (gzip or brotli)
+///
+/// Skips bodies under [`COMPRESSION_MIN_SIZE`] bytes, where the encoding
+/// overhead outweighs the saving, and content that is already compressed or
+/// streamed.
+pub fn compression_layer() -> CompressionLayer<impl Predicate> {
+    CompressionLayer::new().compress_when(
+        SizeAbove::new(COMPRESSION_MIN_SIZE)
+            .and(NotForContentType::IMAGES)
+            .and(NotForContentType::SSE),
+    )
+}
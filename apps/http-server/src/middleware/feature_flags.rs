@@ -0,0 +1,60 @@
+use axum::{
+    extract::{FromRequestParts, Request},
+    http::request::Parts,
+    middleware::Next,
+    response::Response,
+};
+use std::collections::HashSet;
+use std::convert::Infallible;
+use std::sync::Arc;
+
+/// Header listing the feature flags enabled for a request, comma separated
+pub const FEATURE_FLAGS_HEADER: &str = "x-feature-flags";
+
+/// Flag making create endpoints return the full entity instead of just its ID
+pub const RETURN_FULL_ENTITY: &str = "return_full_entity";
+
+/// Feature flags enabled for the current request
+#[derive(Debug, Clone, Default)]
+pub struct Flags(Arc<HashSet<String>>);
+
+impl Flags {
+    /// Parse `flagA, flagB`; names are trimmed and lowercased, blanks ignored
+    pub fn parse(header: &str) -> Self {
+        let flags = header
+            .split(',')
+            .map(|flag| flag.trim().to_lowercase())
+            .filter(|flag| !flag.is_empty())
+            .collect();
+        Self(Arc::new(flags))
+    }
+
+    pub fn is_enabled(&self, flag: &str) -> bool {
+        self.0.contains(flag)
+    }
+}
+
+/// Reads [`FEATURE_FLAGS_HEADER`] into the request extensions as [`Flags`]
+///
+/// Lets new behaviour be rolled out per request without global config.
+/// Requests without the header get an empty set.
+pub async fn feature_flags_middleware(mut request: Request, next: Next) -> Response {
+    let flags = request
+        .headers()
+        .get(FEATURE_FLAGS_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(Flags::parse)
+        .unwrap_or_default();
+    request.extensions_mut().insert(flags);
+
+    next.run(request).await
+}
+
+/// Extractor for the request's [`Flags`]; empty when the middleware is absent
+impl<S: Send + Sync> FromRequestParts<S> for Flags {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts.extensions.get::<Flags>().cloned().unwrap_or_default())
+    }
+}
@@ -0,0 +1,19 @@
+use axum::{extract::Request, http::header, middleware::Next, response::Response};
+use shared::Locale;
+
+/// Negotiates the [`Locale`] from `Accept-Language` for the rest of the request
+///
+/// Error responses built while handling the request pick their titles and
+/// fixed messages in that locale. The locale is also put in the request
+/// extensions for handlers that need it.
+pub async fn locale_middleware(mut request: Request, next: Next) -> Response {
+    let locale = request
+        .headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+        .map(Locale::from_accept_language)
+        .unwrap_or_default();
+    request.extensions_mut().insert(locale);
+
+    locale.scope(next.run(request)).await
+}
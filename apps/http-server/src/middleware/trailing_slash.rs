@@ -0,0 +1,12 @@
+use axum::Router;
+use tower_http::normalize_path::NormalizePath;
+
+/// Serve `/api/parties/list/` exactly like `/api/parties/list`
+///
+/// Trailing slashes are trimmed from the request path before routing, so every
+/// route answers with or without one; the query string is left as is. This has
+/// to wrap the whole router: a `Router::layer` only runs after a route has
+/// matched, by which point the slashed path has already fallen through to 404.
+pub fn trim_trailing_slash(router: Router) -> NormalizePath<Router> {
+    NormalizePath::trim_trailing_slash(router)
+}
@@ -0,0 +1,82 @@
+use crate::app_state::AppState;
+use axum::{
+    extract::{FromRequestParts, Request},
+    http::request::Parts,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use shared::AppError;
+use sqlx::{Postgres, Transaction};
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+type TxSlot = Arc<Mutex<Option<Transaction<'static, Postgres>>>>;
+
+/// Per-request transaction middleware
+///
+/// Installs an empty transaction slot in the request extensions. Handlers opt in
+/// by extracting [`Tx`], which begins the transaction lazily on first use.
+/// After the handler returns, the transaction is committed on a 2xx response
+/// and rolled back otherwise. Requests that never extract [`Tx`] pay nothing.
+pub async fn transaction_middleware(mut request: Request, next: Next) -> Response {
+    let slot: TxSlot = Arc::new(Mutex::new(None));
+    request.extensions_mut().insert(slot.clone());
+
+    let response = next.run(request).await;
+
+    let Some(tx) = slot.lock().await.take() else {
+        return response;
+    };
+
+    let outcome = if response.status().is_success() {
+        tx.commit().await
+    } else {
+        tx.rollback().await
+    };
+
+    match outcome {
+        Ok(()) => response,
+        Err(err) => AppError::from(err).into_response(),
+    }
+}
+
+/// Extractor giving a handler access to the request-scoped transaction
+///
+/// Dereferences to `Transaction`, so `&mut *tx` can be passed anywhere an
+/// executor-generic repository or use case expects an `Acquire` implementor.
+pub struct Tx(OwnedMutexGuard<Option<Transaction<'static, Postgres>>>);
+
+impl FromRequestParts<Arc<AppState>> for Tx {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let slot = parts.extensions.get::<TxSlot>().cloned().ok_or_else(|| {
+            AppError::Internal("Transaction middleware is not installed".to_string())
+        })?;
+
+        let mut guard = slot.lock_owned().await;
+        if guard.is_none() {
+            *guard = Some(state.pool.begin().await?);
+        }
+
+        Ok(Self(guard))
+    }
+}
+
+impl Deref for Tx {
+    type Target = Transaction<'static, Postgres>;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref().expect("transaction is begun on extraction")
+    }
+}
+
+impl DerefMut for Tx {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.0.as_mut().expect("transaction is begun on extraction")
+    }
+}
@@ -0,0 +1,93 @@
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+use axum::http::{HeaderName, HeaderValue};
+use axum::response::{IntoResponseParts, ResponseParts};
+use std::convert::Infallible;
+
+/// Request header stating client preferences (RFC 7240)
+pub const PREFER: HeaderName = HeaderName::from_static("prefer");
+
+/// Response header confirming which preferences were honoured (RFC 7240)
+pub const PREFERENCE_APPLIED: HeaderName = HeaderName::from_static("preference-applied");
+
+/// How much of a created or updated resource the client wants back
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnPreference {
+    /// Just the identifier
+    Minimal,
+    /// The full entity
+    Representation,
+}
+
+impl ReturnPreference {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReturnPreference::Minimal => "return=minimal",
+            ReturnPreference::Representation => "return=representation",
+        }
+    }
+}
+
+/// The `return` preference from the request's `Prefer` headers, if any
+///
+/// Unknown preferences are ignored, as RFC 7240 requires; the first `return`
+/// wins when several are sent.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Prefer(pub Option<ReturnPreference>);
+
+impl Prefer {
+    /// The caller's preference, falling back to `default` when none was sent
+    pub fn return_or(&self, default: ReturnPreference) -> ReturnPreference {
+        self.0.unwrap_or(default)
+    }
+
+    /// `Preference-Applied` echoing the request's preference, when there was one
+    pub fn applied(&self) -> AppliedPreference {
+        AppliedPreference(self.0)
+    }
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for Prefer {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let preference = parts
+            .headers
+            .get_all(PREFER)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .find_map(|token| {
+                let token = token.split(';').next().unwrap_or_default();
+                let (name, value) = token.split_once('=')?;
+                if !name.trim().eq_ignore_ascii_case("return") {
+                    return None;
+                }
+                match value.trim().trim_matches('"').to_ascii_lowercase().as_str() {
+                    "minimal" => Some(ReturnPreference::Minimal),
+                    "representation" => Some(ReturnPreference::Representation),
+                    _ => None,
+                }
+            });
+
+        Ok(Self(preference))
+    }
+}
+
+/// Sets `Preference-Applied` when the request stated a `return` preference
+#[derive(Debug, Clone, Copy)]
+pub struct AppliedPreference(Option<ReturnPreference>);
+
+impl IntoResponseParts for AppliedPreference {
+    type Error = Infallible;
+
+    fn into_response_parts(self, mut res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        if let Some(preference) = self.0 {
+            res.headers_mut().insert(
+                PREFERENCE_APPLIED,
+                HeaderValue::from_static(preference.as_str()),
+            );
+        }
+        Ok(res)
+    }
+}
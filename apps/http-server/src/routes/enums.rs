@@ -0,0 +1,10 @@
+use crate::app_state::AppState;
+use crate::handlers::enums;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+/// GET    /api/enums                 - All enum picklists
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new().routes(routes!(enums::list_enums))
+}
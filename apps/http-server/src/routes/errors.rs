@@ -0,0 +1,10 @@
+use crate::app_state::AppState;
+use crate::handlers::errors;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+/// GET    /api/errors                - Error code catalog
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new().routes(routes!(errors::list_error_codes))
+}
@@ -0,0 +1,12 @@
+use crate::app_state::AppState;
+use crate::handlers::health;
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+use utoipa_axum::routes;
+
+/// Operational probes, mounted at the root rather than under /api
+///
+/// GET    /ready                     - Readiness (add ?deep=true to verify migrations)
+pub fn routes() -> OpenApiRouter<Arc<AppState>> {
+    OpenApiRouter::new().routes(routes!(health::ready))
+}
@@ -1,15 +1,32 @@
+pub mod enums;
+pub mod errors;
+pub mod health;
 pub mod party;
 
 use crate::app_state::AppState;
+use crate::middleware::feature_flags::feature_flags_middleware;
+use crate::middleware::locale::locale_middleware;
+use crate::middleware::transaction::transaction_middleware;
 use std::sync::Arc;
 use utoipa_axum::router::OpenApiRouter;
 
 /// Create all API routes with OpenAPI documentation
 /// Hybrid REST verbs + RPC-style action paths
+///
+/// Paths are registered without a trailing slash; the server wraps the router
+/// in [`trim_trailing_slash`](crate::middleware::trailing_slash::trim_trailing_slash)
+/// so `/api/parties/list/` resolves to the same route.
 pub fn api_routes() -> OpenApiRouter<Arc<AppState>> {
-    OpenApiRouter::new().nest("/api/parties", party::routes())
-    // Add more resources here
-    // .nest("/api/contacts", contact::routes())
-    // .nest("/api/invoices", invoice::routes())
-    // .nest("/api/products", product::routes())
+    OpenApiRouter::new()
+        .merge(health::routes())
+        .merge(enums::routes())
+        .merge(errors::routes())
+        .nest("/api/parties", party::routes())
+        // Add more resources here
+        // .nest("/api/contacts", contact::routes())
+        // .nest("/api/invoices", invoice::routes())
+        // .nest("/api/products", product::routes())
+        .layer(axum::middleware::from_fn(transaction_middleware))
+        .layer(axum::middleware::from_fn(feature_flags_middleware))
+        .layer(axum::middleware::from_fn(locale_middleware))
 }
@@ -8,8 +8,17 @@ use utoipa_axum::routes;
 /// Uses proper HTTP verbs (GET, POST, PUT, DELETE) with action-based paths
 ///
 /// GET    /api/parties/list          - List all parties
+/// GET    /api/parties/status-summary - Count parties by active state
+/// GET    /api/parties/export.ndjson - Stream all parties as NDJSON
 /// GET    /api/parties/get/:id       - Get party by ID  
 /// POST   /api/parties/create        - Create new party
+/// POST   /api/parties/batch         - Create several parties
+/// POST   /api/parties/bulk-update   - Set fields on many parties
+/// POST   /api/parties/first-or-create - Get or create a party by TIN
+/// PUT    /api/parties/:id/legal-name - Set or clear the legal name
+/// PUT    /api/parties/:id/type      - Change the party type
+/// POST   /api/parties/:id/tags      - Add tags
+/// DELETE /api/parties/:id/tags/:tag - Remove a tag
 /// PUT    /api/parties/update/:id    - Update party
 /// DELETE /api/parties/delete/:id    - Delete party
 /// PUT    /api/parties/activate/:id  - Activate party
@@ -17,8 +26,17 @@ use utoipa_axum::routes;
 pub fn routes() -> OpenApiRouter<Arc<AppState>> {
     OpenApiRouter::new()
         .routes(routes!(party::list_parties))
+        .routes(routes!(party::get_party_status_summary))
+        .routes(routes!(party::export_parties_ndjson))
         .routes(routes!(party::get_party))
         .routes(routes!(party::create_party))
+        .routes(routes!(party::batch_create_parties))
+        .routes(routes!(party::bulk_update_parties))
+        .routes(routes!(party::first_or_create_party))
+        .routes(routes!(party::update_party_legal_name))
+        .routes(routes!(party::change_party_type))
+        .routes(routes!(party::add_party_tags))
+        .routes(routes!(party::remove_party_tag))
     // .routes(routes!(party::update_party))
     // .routes(routes!(party::delete_party))
     // .routes(routes!(party::activate_party))
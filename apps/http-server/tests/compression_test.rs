@@ -0,0 +1,71 @@
+//! Integration tests for response compression
+//!
+//! Uses a shared test database with #[tokio::test].
+
+mod common;
+
+use application::ports::PartyRepository;
+use axum::{
+    Router,
+    body::Body,
+    http::{Request, Response, header},
+};
+use common::{get_test_pool, unique_name};
+use domain::clock::SystemClock;
+use domain::party::{DisplayName, Party, PartyType};
+use http_server::middleware::compression::compression_layer;
+use infrastructure::repositories::PartyRepositoryImpl;
+use sqlx::PgPool;
+use tower::ServiceExt;
+
+// =============================================================================
+// Test Setup
+// =============================================================================
+
+fn app(pool: PgPool) -> Router {
+    common::app(pool).layer(compression_layer())
+}
+
+async fn get_gzip(app: &Router, path: &str) -> Response<Body> {
+    let req = Request::builder()
+        .method("GET")
+        .uri(path)
+        .header(header::ACCEPT_ENCODING, "gzip")
+        .body(Body::empty())
+        .unwrap();
+
+    app.clone().oneshot(req).await.unwrap()
+}
+
+// =============================================================================
+// Compression
+// =============================================================================
+
+#[tokio::test]
+async fn large_list_is_gzip_encoded() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+    for _ in 0..20 {
+        let party = Party::new(
+            PartyType::Company,
+            DisplayName::new(unique_name("Compressed")).unwrap(),
+            &SystemClock,
+        );
+        repo.create(&pool, &party).await.unwrap();
+    }
+    let app = app(pool);
+
+    let resp = get_gzip(&app, "/api/parties/list?page-size=20").await;
+
+    assert_eq!(resp.headers()[header::CONTENT_ENCODING], "gzip");
+}
+
+#[tokio::test]
+async fn small_response_is_not_compressed() {
+    let pool = get_test_pool().await;
+    let app = app(pool);
+
+    let resp = get_gzip(&app, "/ready").await;
+
+    assert!(resp.headers().get(header::CONTENT_ENCODING).is_none());
+}
@@ -0,0 +1,36 @@
+//! API integration tests for the enum options endpoint
+//!
+//! Uses a shared test database with #[tokio::test].
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{app, get_json, get_test_pool};
+use serde_json::json;
+
+// =============================================================================
+// GET /api/enums
+// =============================================================================
+
+#[tokio::test]
+async fn list_enums_contains_party_type() {
+    let pool = get_test_pool().await;
+    let app = app(pool);
+
+    let (status, body) = get_json(&app, "/api/enums").await;
+
+    assert_eq!(status, StatusCode::OK);
+    let party_type = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["name"] == "party_type")
+        .expect("party_type enum is registered");
+    assert_eq!(
+        party_type["options"],
+        json!([
+            { "value": "company", "label": "Company" },
+            { "value": "person", "label": "Person" }
+        ])
+    );
+}
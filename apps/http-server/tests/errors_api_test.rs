@@ -0,0 +1,50 @@
+//! API integration tests for the error catalog endpoint
+//!
+//! Uses a shared test database with #[tokio::test].
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{app, get_json, get_test_pool};
+
+// =============================================================================
+// GET /api/errors
+// =============================================================================
+
+#[tokio::test]
+async fn list_error_codes_describes_not_found() {
+    let pool = get_test_pool().await;
+    let app = app(pool);
+
+    let (status, body) = get_json(&app, "/api/errors").await;
+
+    assert_eq!(status, StatusCode::OK);
+    let not_found = body["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["code"] == "not_found")
+        .expect("not_found is in the catalog");
+    assert_eq!(not_found["status"], 404);
+    assert_eq!(not_found["title"], "Not Found");
+    assert!(not_found["description"].is_string());
+}
+
+#[tokio::test]
+async fn not_found_response_matches_catalog_entry() {
+    let pool = get_test_pool().await;
+    let app = app(pool);
+
+    let (_, catalog) = get_json(&app, "/api/errors").await;
+    let (status, error) =
+        get_json(&app, &format!("/api/parties/get/{}", uuid::Uuid::now_v7())).await;
+
+    let entry = catalog["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| error["type"] == format!("urn:error:{}", e["code"].as_str().unwrap()))
+        .expect("returned code is in the catalog");
+    assert_eq!(entry["status"], status.as_u16());
+    assert_eq!(entry["title"], error["title"]);
+}
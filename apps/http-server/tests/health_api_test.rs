@@ -0,0 +1,62 @@
+//! API integration tests for health endpoints
+//!
+//! Uses a shared test database with #[tokio::test].
+
+mod common;
+
+use axum::http::StatusCode;
+use common::{app, database_url, get_json, get_test_pool};
+use sqlx::postgres::PgPoolOptions;
+
+// =============================================================================
+// GET /ready
+// =============================================================================
+
+#[tokio::test]
+async fn ready_returns_ok() {
+    let pool = get_test_pool().await;
+    let app = app(pool);
+
+    let (status, body) = get_json(&app, "/ready").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["status"], "ready");
+}
+
+#[tokio::test]
+async fn deep_ready_returns_ok_after_migrations() {
+    let pool = get_test_pool().await;
+    let app = app(pool);
+
+    let (status, body) = get_json(&app, "/ready?deep=true").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["status"], "ready");
+}
+
+#[tokio::test]
+async fn deep_ready_fails_on_unmigrated_database() {
+    let admin = get_test_pool().await;
+    let db_name = format!("_sqlx_test_ready_{}", uuid::Uuid::now_v7().simple());
+    sqlx::query(&format!("CREATE DATABASE \"{db_name}\""))
+        .execute(&admin)
+        .await
+        .unwrap();
+
+    let url = format!("{}/{}", database_url().rsplit_once('/').unwrap().0, db_name);
+    let pool = PgPoolOptions::new().connect(&url).await.unwrap();
+    let app = app(pool.clone());
+
+    let (shallow_status, _) = get_json(&app, "/ready").await;
+    let (deep_status, body) = get_json(&app, "/ready?deep=true").await;
+
+    pool.close().await;
+    sqlx::query(&format!("DROP DATABASE \"{db_name}\" WITH (FORCE)"))
+        .execute(&admin)
+        .await
+        .unwrap();
+
+    assert_eq!(shallow_status, StatusCode::OK);
+    assert_eq!(deep_status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(body["type"], "urn:error:service_unavailable");
+}
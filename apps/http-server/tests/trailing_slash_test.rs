@@ -0,0 +1,71 @@
+//! Integration tests for trailing slash normalization
+//!
+//! Uses a shared test database with #[tokio::test].
+
+mod common;
+
+use application::ports::PartyRepository;
+use axum::{Router, http::StatusCode};
+use common::{get_json, get_test_pool, unique_name};
+use domain::clock::SystemClock;
+use domain::party::{DisplayName, Party, PartyType};
+use http_server::middleware::trailing_slash::trim_trailing_slash;
+use infrastructure::repositories::PartyRepositoryImpl;
+use sqlx::PgPool;
+use tower_http::normalize_path::NormalizePath;
+
+// =============================================================================
+// Test Setup
+// =============================================================================
+
+fn app(pool: PgPool) -> NormalizePath<Router> {
+    trim_trailing_slash(common::app(pool))
+}
+
+// =============================================================================
+// Trailing Slash
+// =============================================================================
+
+#[tokio::test]
+async fn list_with_trailing_slash_matches_list_without() {
+    let pool = get_test_pool().await;
+    let name = unique_name("Slash");
+    let party = Party::new(
+        PartyType::Company,
+        DisplayName::new(name.clone()).unwrap(),
+        &SystemClock,
+    );
+    PartyRepositoryImpl::new()
+        .create(&pool, &party)
+        .await
+        .unwrap();
+    let app = app(pool);
+
+    let (status, without) = get_json(&app, &format!("/api/parties/list?search={name}")).await;
+    let (slash_status, with) = get_json(&app, &format!("/api/parties/list/?search={name}")).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(slash_status, StatusCode::OK);
+    assert_eq!(with["data"], without["data"]);
+    assert_eq!(with["data"][0]["id"], party.id().to_string());
+}
+
+#[tokio::test]
+async fn path_parameter_route_accepts_trailing_slash() {
+    let pool = get_test_pool().await;
+    let party = Party::new(
+        PartyType::Company,
+        DisplayName::new(unique_name("SlashGet")).unwrap(),
+        &SystemClock,
+    );
+    PartyRepositoryImpl::new()
+        .create(&pool, &party)
+        .await
+        .unwrap();
+    let app = app(pool);
+
+    let (status, body) = get_json(&app, &format!("/api/parties/get/{}/", party.id())).await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["data"]["id"], party.id().to_string());
+}
@@ -0,0 +1,137 @@
+//! Integration tests for the per-request transaction middleware
+//!
+//! Uses a shared test database with #[tokio::test].
+
+mod common;
+
+use application::ports::PartyRepository;
+use axum::{
+    Extension, Router,
+    body::Body,
+    http::{Request, StatusCode},
+    middleware::from_fn,
+    response::IntoResponse,
+    routing::post,
+};
+use common::{get_test_pool, unique_name};
+use domain::clock::SystemClock;
+use domain::party::{DisplayName, Party, PartyType};
+use http_server::{
+    app_state::AppState,
+    config::Config,
+    middleware::transaction::{Tx, transaction_middleware},
+};
+use infrastructure::repositories::PartyRepositoryImpl;
+use shared::{AppError, created};
+use sqlx::PgPool;
+use std::sync::{Arc, Mutex};
+use tower::ServiceExt;
+
+// =============================================================================
+// Test Setup
+// =============================================================================
+
+/// Parties written by the test handlers, captured so tests can look them up
+type Written = Arc<Mutex<Vec<Party>>>;
+
+struct TestState {
+    app_state: Arc<AppState>,
+    written: Written,
+}
+
+/// Handler performing two writes in the request transaction, optionally failing afterwards
+async fn two_writes(
+    Extension(written): Extension<Written>,
+    Extension(fail): Extension<bool>,
+    mut tx: Tx,
+) -> Result<impl IntoResponse, AppError> {
+    let repo = PartyRepositoryImpl::new();
+
+    for prefix in ["TxFirst", "TxSecond"] {
+        let party = Party::new(
+            PartyType::Company,
+            DisplayName::new(unique_name(prefix)).unwrap(),
+            &SystemClock,
+        );
+        repo.create(&mut *tx, &party).await?;
+        written.lock().unwrap().push(party);
+    }
+
+    if fail {
+        return Err(AppError::Internal("forced failure".to_string()));
+    }
+
+    Ok(created(()))
+}
+
+fn app(pool: PgPool, fail: bool) -> (Router, TestState) {
+    let app_state = Arc::new(AppState {
+        pool,
+        config: Config::default(),
+    });
+    let written: Written = Arc::default();
+
+    let router = Router::new()
+        .route("/two-writes", post(two_writes))
+        .layer(Extension(written.clone()))
+        .layer(Extension(fail))
+        .layer(from_fn(transaction_middleware))
+        .with_state(app_state.clone());
+
+    (router, TestState { app_state, written })
+}
+
+async fn post_empty(app: &Router, path: &str) -> StatusCode {
+    let req = Request::builder()
+        .method("POST")
+        .uri(path)
+        .body(Body::empty())
+        .unwrap();
+
+    app.clone().oneshot(req).await.unwrap().status()
+}
+
+async fn persisted(state: &TestState) -> Vec<Option<Party>> {
+    let repo = PartyRepositoryImpl::new();
+    let parties = state.written.lock().unwrap().clone();
+
+    let mut found = Vec::with_capacity(parties.len());
+    for party in parties {
+        found.push(
+            repo.find_by_id(&state.app_state.pool, party.id(), false)
+                .await
+                .unwrap(),
+        );
+    }
+    found
+}
+
+// =============================================================================
+// Commit / Rollback
+// =============================================================================
+
+#[tokio::test]
+async fn successful_handler_commits_all_writes() {
+    let pool = get_test_pool().await;
+    let (app, state) = app(pool, false);
+
+    let status = post_empty(&app, "/two-writes").await;
+
+    assert_eq!(status, StatusCode::CREATED);
+    let found = persisted(&state).await;
+    assert_eq!(found.len(), 2);
+    assert!(found.iter().all(Option::is_some));
+}
+
+#[tokio::test]
+async fn failing_handler_rolls_back_all_writes() {
+    let pool = get_test_pool().await;
+    let (app, state) = app(pool, true);
+
+    let status = post_empty(&app, "/two-writes").await;
+
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+    let found = persisted(&state).await;
+    assert_eq!(found.len(), 2);
+    assert!(found.iter().all(Option::is_none));
+}
@@ -5,11 +5,27 @@ pub mod ports {
 }
 
 pub mod party {
+    pub mod add_party_tags;
+    pub mod bulk_update_parties;
+    pub mod change_party_type;
     pub mod create_party;
+    pub mod export_parties;
+    pub mod first_or_create_party;
     pub mod get_party;
+    pub mod get_party_status_summary;
     pub mod list_parties;
+    pub mod remove_party_tag;
+    pub mod update_party_legal_name;
 
+    pub use add_party_tags::*;
+    pub use bulk_update_parties::*;
+    pub use change_party_type::*;
     pub use create_party::*;
+    pub use export_parties::*;
+    pub use first_or_create_party::*;
     pub use get_party::*;
+    pub use get_party_status_summary::*;
     pub use list_parties::*;
+    pub use remove_party_tag::*;
+    pub use update_party_legal_name::*;
 }
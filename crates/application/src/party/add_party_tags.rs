@@ -0,0 +1,60 @@
+use crate::ports::PartyRepository;
+use domain::party::value_objects::Tag;
+use shared::{AppError, ValidationError};
+use std::collections::BTreeSet;
+use uuid::Uuid;
+
+/// Attach labels to a party
+pub struct AddPartyTagsUseCase<R> {
+    repository: R,
+}
+
+impl<R: PartyRepository> AddPartyTagsUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    /// Tags are lowercased and deduplicated; returns every tag the party now has
+    pub async fn execute<'a, E>(
+        &self,
+        executor: E,
+        id: Uuid,
+        tags: Vec<String>,
+    ) -> Result<Vec<Tag>, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        if tags.is_empty() {
+            return Err(ValidationError::new("No tags to add")
+                .with_field("tags", "Must not be empty")
+                .into());
+        }
+        let tags = tags
+            .into_iter()
+            .enumerate()
+            .map(|(i, tag)| {
+                Tag::new(tag).map_err(|err| ValidationError::for_field(format!("tags[{i}]"), err))
+            })
+            .collect::<Result<BTreeSet<_>, _>>()?;
+        let tags: Vec<Tag> = tags.into_iter().collect();
+
+        let mut tx = executor.begin().await?;
+        if self
+            .repository
+            .find_by_id(&mut *tx, id, false)
+            .await?
+            .is_none()
+        {
+            return Err(AppError::NotFound(format!(
+                "Party with ID {} not found",
+                id
+            )));
+        }
+
+        self.repository.add_tags(&mut *tx, id, &tags).await?;
+        let all_tags = self.repository.find_tags(&mut *tx, id).await?;
+        tx.commit().await?;
+
+        Ok(all_tags)
+    }
+}
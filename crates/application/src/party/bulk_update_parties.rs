@@ -0,0 +1,51 @@
+use crate::ports::{PartyChanges, PartyRepository};
+use domain::clock::{Clock, SystemClock};
+use shared::{AppError, ValidationError};
+use uuid::Uuid;
+
+/// Set the same whitelisted columns on many parties at once
+pub struct BulkUpdatePartiesUseCase<R, C = SystemClock> {
+    repository: R,
+    clock: C,
+}
+
+impl<R: PartyRepository> BulkUpdatePartiesUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self::with_clock(repository, SystemClock)
+    }
+}
+
+impl<R: PartyRepository, C: Clock> BulkUpdatePartiesUseCase<R, C> {
+    pub fn with_clock(repository: R, clock: C) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Returns the number of parties changed
+    pub async fn execute<'a, E>(
+        &self,
+        executor: E,
+        mut ids: Vec<Uuid>,
+        changes: PartyChanges,
+    ) -> Result<u64, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        if ids.is_empty() {
+            return Err(ValidationError::new("No parties to update")
+                .with_field("ids", "Must not be empty")
+                .into());
+        }
+        if changes.is_empty() {
+            return Err(ValidationError::new("Nothing to update")
+                .with_field("set", "Must set at least one field")
+                .into());
+        }
+
+        ids.sort_unstable();
+        ids.dedup();
+
+        self.repository
+            .bulk_update(executor, &ids, &changes, self.clock.now())
+            .await
+    }
+}
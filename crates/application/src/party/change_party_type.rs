@@ -0,0 +1,59 @@
+use crate::ports::PartyRepository;
+use domain::clock::{Clock, SystemClock};
+use domain::party::Party;
+use domain::party::value_objects::PartyType;
+use shared::AppError;
+use uuid::Uuid;
+
+/// Outcome of [`ChangePartyTypeUseCase::execute`]
+#[derive(Debug)]
+pub struct PartyTypeChange {
+    pub party: Party,
+    /// JSON names of fields the new type cannot carry and that were cleared
+    pub cleared_fields: Vec<&'static str>,
+}
+
+/// Move a party created as the wrong type to the right one
+pub struct ChangePartyTypeUseCase<R, C = SystemClock> {
+    repository: R,
+    clock: C,
+}
+
+impl<R: PartyRepository> ChangePartyTypeUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self::with_clock(repository, SystemClock)
+    }
+}
+
+impl<R: PartyRepository, C: Clock> ChangePartyTypeUseCase<R, C> {
+    pub fn with_clock(repository: R, clock: C) -> Self {
+        Self { repository, clock }
+    }
+
+    /// The new type and the clearing of fields it cannot carry are saved together
+    pub async fn execute<'a, E>(
+        &self,
+        executor: E,
+        id: Uuid,
+        party_type: PartyType,
+    ) -> Result<PartyTypeChange, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        let mut tx = executor.begin().await?;
+        let mut party = self
+            .repository
+            .find_by_id(&mut *tx, id, false)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Party with ID {} not found", id)))?;
+
+        let cleared_fields = party.change_type(party_type, &self.clock);
+        self.repository.update(&mut *tx, &party).await?;
+        tx.commit().await?;
+
+        Ok(PartyTypeChange {
+            party,
+            cleared_fields,
+        })
+    }
+}
@@ -1,10 +1,47 @@
 use crate::ports::PartyRepository;
-use domain::party::value_objects::{DisplayName, LegalName, PartyType, RegistrationNumber, Tin};
+use domain::clock::{Clock, SystemClock};
 use domain::party::Party;
-use shared::AppError;
+use domain::party::value_objects::{DisplayName, LegalName, PartyType, RegistrationNumber, Tin};
+use shared::{AppError, DomainError, ValidationError};
+use std::str::FromStr;
+
+/// Attach the offending request field to a value-object failure
+pub(crate) fn field_error(field: &'static str) -> impl FnOnce(DomainError) -> AppError {
+    move |err| ValidationError::for_field(field, err).into()
+}
+
+/// Reject field combinations that are valid on their own but not together
+///
+/// Collects every conflict so the client sees them all at once. TIN and
+/// registration number are company-only, matching what `Party::change_type`
+/// clears when a party becomes a person.
+fn check_conflicts(
+    party_type: PartyType,
+    tin: Option<&Tin>,
+    registration_number: Option<&RegistrationNumber>,
+) -> Result<(), ValidationError> {
+    let mut error = ValidationError::new("Conflicting fields");
+
+    if party_type == PartyType::Person && tin.is_some() {
+        error.add_field("tin", "Only companies have a TIN");
+    }
+    if party_type == PartyType::Person && registration_number.is_some() {
+        error.add_field(
+            "registrationNumber",
+            "Only companies have a business registration number",
+        );
+    }
+
+    if error.fields.is_empty() {
+        Ok(())
+    } else {
+        Err(error)
+    }
+}
 
-pub struct CreatePartyUseCase<R> {
+pub struct CreatePartyUseCase<R, C = SystemClock> {
     repository: R,
+    clock: C,
 }
 
 pub struct CreatePartyInput {
@@ -17,7 +54,14 @@ pub struct CreatePartyInput {
 
 impl<R: PartyRepository> CreatePartyUseCase<R> {
     pub fn new(repository: R) -> Self {
-        Self { repository }
+        Self::with_clock(repository, SystemClock)
+    }
+}
+
+impl<R: PartyRepository, C: Clock> CreatePartyUseCase<R, C> {
+    /// Use case stamping timestamps from `clock` instead of the system time
+    pub fn with_clock(repository: R, clock: C) -> Self {
+        Self { repository, clock }
     }
 
     pub async fn execute<'a, E>(
@@ -29,30 +73,37 @@ impl<R: PartyRepository> CreatePartyUseCase<R> {
         E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
     {
         // Validate and create value objects
-        let party_type = PartyType::from_str(&input.party_type)?;
-        let display_name = DisplayName::new(input.display_name)?;
+        let party_type =
+            PartyType::from_str(&input.party_type).map_err(field_error("partyType"))?;
+        let display_name =
+            DisplayName::new(input.display_name).map_err(field_error("displayName"))?;
 
         // Convert empty strings to None, and validate if not empty
         let legal_name = if input.legal_name.trim().is_empty() {
             None
         } else {
-            Some(LegalName::new(input.legal_name)?)
+            Some(LegalName::new(input.legal_name).map_err(field_error("legalName"))?)
         };
 
         let tin = if input.tin.trim().is_empty() {
             None
         } else {
-            Some(Tin::new(input.tin)?)
+            Some(Tin::new(input.tin).map_err(field_error("tin"))?)
         };
 
         let registration_number = if input.registration_number.trim().is_empty() {
             None
         } else {
-            Some(RegistrationNumber::new(input.registration_number)?)
+            Some(
+                RegistrationNumber::new(input.registration_number)
+                    .map_err(field_error("registrationNumber"))?,
+            )
         };
 
+        check_conflicts(party_type, tin.as_ref(), registration_number.as_ref())?;
+
         // Create party entity
-        let base_party = Party::new(party_type, display_name.clone());
+        let base_party = Party::new(party_type, display_name.clone(), &self.clock);
 
         // Apply optional fields through reconstruction
         let party = Party::from_storage(
@@ -0,0 +1,19 @@
+use crate::ports::PartyRepository;
+use domain::party::Party;
+use futures_util::stream::BoxStream;
+use shared::AppError;
+
+pub struct ExportPartiesUseCase<R> {
+    repository: R,
+}
+
+impl<R: PartyRepository> ExportPartiesUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    /// Every not deleted party, streamed row by row from the database
+    pub fn execute<'a>(&'a self, pool: &'a sqlx::PgPool) -> BoxStream<'a, Result<Party, AppError>> {
+        self.repository.stream_all(pool)
+    }
+}
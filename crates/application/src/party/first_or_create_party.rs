@@ -0,0 +1,61 @@
+use super::create_party::{CreatePartyInput, CreatePartyUseCase, field_error};
+use crate::ports::PartyRepository;
+use domain::party::Party;
+use domain::party::value_objects::Tin;
+use shared::{AppError, ValidationError};
+
+/// Whether [`FirstOrCreatePartyUseCase`] returned an existing party or a new one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FirstOrCreateOutcome {
+    Found,
+    Created,
+}
+
+/// Get-or-create a party keyed by TIN
+///
+/// The lookup and insert run under an advisory lock on the TIN, so concurrent
+/// calls with the same TIN produce exactly one row.
+pub struct FirstOrCreatePartyUseCase<R> {
+    repository: R,
+    create: CreatePartyUseCase<R>,
+}
+
+impl<R: PartyRepository + Clone> FirstOrCreatePartyUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self {
+            create: CreatePartyUseCase::new(repository.clone()),
+            repository,
+        }
+    }
+
+    pub async fn execute<'a, E>(
+        &self,
+        executor: E,
+        input: CreatePartyInput,
+    ) -> Result<(Party, FirstOrCreateOutcome), AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        if input.tin.trim().is_empty() {
+            return Err(AppError::Validation(
+                ValidationError::new("TIN is required to match on TIN")
+                    .with_field("tin", "TIN is required to match on TIN"),
+            ));
+        }
+        let tin = Tin::new(input.tin.as_str()).map_err(field_error("tin"))?;
+
+        let mut tx = executor.begin().await?;
+        self.repository.lock_tin(&mut *tx, &tin).await?;
+
+        let result = match self.repository.find_by_tin(&mut *tx, &tin).await? {
+            Some(party) => (party, FirstOrCreateOutcome::Found),
+            None => (
+                self.create.execute(&mut *tx, input).await?,
+                FirstOrCreateOutcome::Created,
+            ),
+        };
+
+        tx.commit().await?;
+        Ok(result)
+    }
+}
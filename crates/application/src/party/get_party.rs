@@ -12,16 +12,18 @@ impl<R: PartyRepository> GetPartyUseCase<R> {
         Self { repository }
     }
 
+    /// `include_deleted` also returns a soft-deleted party, for admin views
     pub async fn execute<'a, E>(
         &self,
         executor: E,
         id: Uuid,
+        include_deleted: bool,
     ) -> Result<Party, AppError>
     where
         E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
     {
         self.repository
-            .find_by_id(executor, id)
+            .find_by_id(executor, id, include_deleted)
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Party with ID {} not found", id)))
     }
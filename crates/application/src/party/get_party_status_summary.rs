@@ -0,0 +1,19 @@
+use crate::ports::{PartyRepository, PartyStatusCounts};
+use shared::AppError;
+
+pub struct GetPartyStatusSummaryUseCase<R> {
+    repository: R,
+}
+
+impl<R: PartyRepository> GetPartyStatusSummaryUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    pub async fn execute<'a, E>(&self, executor: E) -> Result<PartyStatusCounts, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        self.repository.count_by_status(executor).await
+    }
+}
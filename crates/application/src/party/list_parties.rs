@@ -1,6 +1,6 @@
-use crate::ports::PartyRepository;
+use crate::ports::{PartyFilter, PartyRepository};
 use domain::party::Party;
-use shared::{AppError, PaginationMeta};
+use shared::{AppError, ListResult, Pagination};
 
 pub struct ListPartiesUseCase<R> {
     repository: R,
@@ -14,14 +14,14 @@ impl<R: PartyRepository> ListPartiesUseCase<R> {
     pub async fn execute<'a, E>(
         &self,
         executor: E,
-        page: u32,
-        page_size: u32,
-    ) -> Result<(Vec<Party>, PaginationMeta), AppError>
+        pagination: Pagination,
+        filter: PartyFilter,
+    ) -> Result<ListResult<Party>, AppError>
     where
         E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
     {
         self.repository
-            .find_paginated(executor, page, page_size)
+            .find_paginated(executor, pagination, &filter)
             .await
     }
 }
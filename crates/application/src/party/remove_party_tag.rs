@@ -0,0 +1,42 @@
+use super::create_party::field_error;
+use crate::ports::PartyRepository;
+use domain::party::value_objects::Tag;
+use shared::AppError;
+use uuid::Uuid;
+
+/// Detach a label from a party
+pub struct RemovePartyTagUseCase<R> {
+    repository: R,
+}
+
+impl<R: PartyRepository> RemovePartyTagUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self { repository }
+    }
+
+    /// Removing a tag the party does not have succeeds without changes
+    pub async fn execute<'a, E>(&self, executor: E, id: Uuid, tag: String) -> Result<(), AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        let tag = Tag::new(tag).map_err(field_error("tag"))?;
+
+        let mut tx = executor.begin().await?;
+        if self
+            .repository
+            .find_by_id(&mut *tx, id, false)
+            .await?
+            .is_none()
+        {
+            return Err(AppError::NotFound(format!(
+                "Party with ID {} not found",
+                id
+            )));
+        }
+
+        self.repository.remove_tag(&mut *tx, id, &tag).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
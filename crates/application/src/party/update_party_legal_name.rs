@@ -0,0 +1,55 @@
+use super::create_party::field_error;
+use crate::ports::PartyRepository;
+use domain::clock::{Clock, SystemClock};
+use domain::party::Party;
+use domain::party::value_objects::LegalName;
+use shared::AppError;
+use uuid::Uuid;
+
+/// Set or clear a party's legal name
+pub struct UpdatePartyLegalNameUseCase<R, C = SystemClock> {
+    repository: R,
+    clock: C,
+}
+
+impl<R: PartyRepository> UpdatePartyLegalNameUseCase<R> {
+    pub fn new(repository: R) -> Self {
+        Self::with_clock(repository, SystemClock)
+    }
+}
+
+impl<R: PartyRepository, C: Clock> UpdatePartyLegalNameUseCase<R, C> {
+    pub fn with_clock(repository: R, clock: C) -> Self {
+        Self { repository, clock }
+    }
+
+    /// `None` or a blank name clears the legal name
+    pub async fn execute<'a, E>(
+        &self,
+        executor: E,
+        id: Uuid,
+        legal_name: Option<String>,
+    ) -> Result<Party, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        let legal_name = legal_name
+            .filter(|name| !name.trim().is_empty())
+            .map(LegalName::new)
+            .transpose()
+            .map_err(field_error("legalName"))?;
+
+        let mut tx = executor.begin().await?;
+        let mut party = self
+            .repository
+            .find_by_id(&mut *tx, id, false)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Party with ID {} not found", id)))?;
+
+        party.update_legal_name(legal_name, &self.clock);
+        self.repository.update(&mut *tx, &party).await?;
+        tx.commit().await?;
+
+        Ok(party)
+    }
+}
@@ -1,9 +1,61 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use domain::party::Party;
-use shared::{AppError, PaginationMeta};
+use domain::party::value_objects::{PartyType, Tag, Tin};
+use futures_util::stream::BoxStream;
+use shared::{AppError, ListResult, Pagination, Sort};
 use uuid::Uuid;
 
+/// Optional predicates applied to party list queries
+///
+/// Every `None` field means "no filter" for that column.
+#[derive(Debug, Clone, Default)]
+pub struct PartyFilter {
+    pub is_active: Option<bool>,
+    /// Full-text search over names and TIN, with a substring fallback on both
+    pub search: Option<String>,
+    /// Only parties carrying this tag
+    pub tag: Option<Tag>,
+    /// Explicit order; when `None`, searches rank by relevance and other
+    /// lists use the repository's default sort
+    pub sort: Option<Sort>,
+}
+
+/// Column values applied by a bulk update
+///
+/// Only the whitelisted columns can be set this way; `None` leaves a column as is.
+/// Setting `party_type` to person also clears `tin` and `registration_number`.
+#[derive(Debug, Clone, Default)]
+pub struct PartyChanges {
+    pub is_active: Option<bool>,
+    pub party_type: Option<PartyType>,
+}
+
+impl PartyChanges {
+    pub fn is_empty(&self) -> bool {
+        self.is_active.is_none() && self.party_type.is_none()
+    }
+}
+
+/// Not deleted parties counted by active state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PartyStatusCounts {
+    pub active: u64,
+    pub inactive: u64,
+}
+
+impl PartyStatusCounts {
+    pub fn total(&self) -> u64 {
+        self.active + self.inactive
+    }
+}
+
 /// Port (interface) for party persistence
+///
+/// A party is visible until it is soft-deleted: every read and write below
+/// skips rows with `deleted_at` set, unless `find_by_id` is asked to include
+/// them, and `is_active` never hides a party.
+/// Filter on `is_active` explicitly through [`PartyFilter`] when needed.
 #[async_trait]
 pub trait PartyRepository: Send + Sync {
     /// Create a new party (INSERT)
@@ -16,27 +68,97 @@ pub trait PartyRepository: Send + Sync {
     where
         E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
 
+    /// Apply `changes` to every party in `ids` with one UPDATE
+    ///
+    /// Returns the number of rows changed; unknown IDs are skipped.
+    async fn bulk_update<'a, E>(
+        &self,
+        executor: E,
+        ids: &[Uuid],
+        changes: &PartyChanges,
+        updated_at: DateTime<Utc>,
+    ) -> Result<u64, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
+
     /// Find party by ID
+    ///
+    /// Soft-deleted parties are skipped unless `include_deleted` is set.
     async fn find_by_id<'a, E>(
         &self,
         executor: E,
         id: Uuid,
+        include_deleted: bool,
     ) -> Result<Option<Party>, AppError>
     where
         E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
 
-    /// Find parties with offset-based pagination
-    /// Returns (items, pagination_meta)
+    /// Find the earliest-created party with the given TIN
+    async fn find_by_tin<'a, E>(&self, executor: E, tin: &Tin) -> Result<Option<Party>, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
+
+    /// Serialize transactions working on the same TIN
+    ///
+    /// Takes a transaction-scoped advisory lock, so `executor` must be inside a
+    /// transaction; the lock is released when it commits or rolls back.
+    async fn lock_tin<'a, E>(&self, executor: E, tin: &Tin) -> Result<(), AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
+
+    /// Find parties matching `filter` with offset-based pagination
+    ///
+    /// A zero page size was already clamped to 1 by `Pagination::new`, and the
+    /// returned metadata reports the page size actually used.
     async fn find_paginated<'a, E>(
         &self,
         executor: E,
-        page: u32,
-        page_size: u32,
-    ) -> Result<(Vec<Party>, PaginationMeta), AppError>
+        pagination: Pagination,
+        filter: &PartyFilter,
+    ) -> Result<ListResult<Party>, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
+
+    /// Count parties by active state in a single query
+    async fn count_by_status<'a, E>(&self, executor: E) -> Result<PartyStatusCounts, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
+
+    /// Stream every party, oldest first, without buffering the table
+    ///
+    /// Holds a pooled connection until the stream is dropped or exhausted.
+    /// Unlike the other methods this takes the pool rather than an
+    /// `Acquire` executor: the stream outlives the call, so it has to check
+    /// out and own its connection instead of borrowing the caller's, and a
+    /// long export should not pin a request transaction open.
+    fn stream_all<'a>(&'a self, pool: &'a sqlx::PgPool) -> BoxStream<'a, Result<Party, AppError>>;
+
+    /// Attach `tags` to a party; tags it already has are ignored
+    async fn add_tags<'a, E>(
+        &self,
+        executor: E,
+        party_id: Uuid,
+        tags: &[Tag],
+    ) -> Result<(), AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
+
+    /// Detach `tag` from a party; a tag it does not have is ignored
+    async fn remove_tag<'a, E>(
+        &self,
+        executor: E,
+        party_id: Uuid,
+        tag: &Tag,
+    ) -> Result<(), AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
+
+    /// All tags on a party, alphabetically
+    async fn find_tags<'a, E>(&self, executor: E, party_id: Uuid) -> Result<Vec<Tag>, AppError>
     where
         E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
 
-    /// Delete party by ID
+    /// Soft-delete party by ID, stamping `deleted_at` and leaving `is_active` as is
     async fn delete<'a, E>(&self, executor: E, id: Uuid) -> Result<(), AppError>
     where
         E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send;
@@ -5,9 +5,12 @@
 use application::party::{
     CreatePartyInput, CreatePartyUseCase, GetPartyUseCase, ListPartiesUseCase,
 };
+use application::ports::PartyFilter;
+use chrono::{TimeZone, Utc};
+use domain::clock::FixedClock;
 use infrastructure::repositories::PartyRepositoryImpl;
-use rstest::fixture;
-use shared::AppError;
+use rstest::{fixture, rstest};
+use shared::{AppError, ListResult, Pagination};
 use sqlx::postgres::PgPoolOptions;
 
 // =============================================================================
@@ -103,6 +106,21 @@ async fn create_party_with_full_data() {
     assert_eq!(party.tin().unwrap().value(), "0123456789");
 }
 
+#[tokio::test]
+async fn create_party_stamps_timestamps_from_clock() {
+    let pool = get_test_pool().await;
+    let at = Utc.with_ymd_and_hms(2025, 1, 15, 10, 30, 0).unwrap();
+    let use_case = CreatePartyUseCase::with_clock(repo(), FixedClock::new(at));
+
+    let party = use_case
+        .execute(&pool, minimal_input()(&unique_name("Clocked")))
+        .await
+        .unwrap();
+
+    assert_eq!(party.created_at(), at);
+    assert_eq!(party.updated_at(), at);
+}
+
 #[tokio::test]
 async fn create_party_fails_with_empty_display_name() {
     let pool = get_test_pool().await;
@@ -113,6 +131,35 @@ async fn create_party_fails_with_empty_display_name() {
     assert!(result.is_err());
 }
 
+#[tokio::test]
+async fn create_party_reports_display_name_field_error() {
+    let pool = get_test_pool().await;
+    let use_case = CreatePartyUseCase::new(repo());
+
+    let result = use_case.execute(&pool, minimal_input()("")).await;
+
+    let Err(AppError::Validation(err)) = result else {
+        panic!("expected validation error, got {:?}", result);
+    };
+    assert_eq!(err.fields.len(), 1);
+    assert_eq!(err.fields[0].field, "displayName");
+}
+
+#[tokio::test]
+async fn create_party_reports_tin_field_error() {
+    let pool = get_test_pool().await;
+    let use_case = CreatePartyUseCase::new(repo());
+    let mut input = minimal_input()(&unique_name("LongTin"));
+    input.tin = "9".repeat(51);
+
+    let result = use_case.execute(&pool, input).await;
+
+    let Err(AppError::Validation(err)) = result else {
+        panic!("expected validation error, got {:?}", result);
+    };
+    assert_eq!(err.fields[0].field, "tin");
+}
+
 #[tokio::test]
 async fn create_party_fails_with_invalid_party_type() {
     let pool = get_test_pool().await;
@@ -125,6 +172,74 @@ async fn create_party_fails_with_invalid_party_type() {
     assert!(result.is_err());
 }
 
+#[rstest]
+#[case("")]
+#[case("   ")]
+#[tokio::test]
+async fn create_party_reports_party_type_field_error_when_blank(#[case] party_type: &str) {
+    let pool = get_test_pool().await;
+    let use_case = CreatePartyUseCase::new(repo());
+    let mut input = minimal_input()(&unique_name("BlankType"));
+    input.party_type = party_type.to_string();
+
+    let result = use_case.execute(&pool, input).await;
+
+    let Err(AppError::Validation(err)) = result else {
+        panic!("expected validation error, got {:?}", result);
+    };
+    assert_eq!(err.fields.len(), 1);
+    assert_eq!(err.fields[0].field, "partyType");
+    assert!(err.fields[0].message.contains("'company' or 'person'"));
+}
+
+#[tokio::test]
+async fn create_person_with_registration_number_is_a_conflict() {
+    let pool = get_test_pool().await;
+    let use_case = CreatePartyUseCase::new(repo());
+    let mut input = minimal_input()(&unique_name("PersonBrn"));
+    input.party_type = "person".to_string();
+    input.registration_number = "BRN-12345".to_string();
+
+    let result = use_case.execute(&pool, input).await;
+
+    let Err(AppError::Validation(err)) = result else {
+        panic!("expected validation error, got {:?}", result);
+    };
+    assert_eq!(err.message, "Conflicting fields");
+    assert_eq!(err.fields.len(), 1);
+    assert_eq!(err.fields[0].field, "registrationNumber");
+}
+
+#[tokio::test]
+async fn create_person_with_company_identifiers_reports_each_conflict() {
+    let pool = get_test_pool().await;
+    let use_case = CreatePartyUseCase::new(repo());
+    let mut input = minimal_input()(&unique_name("PersonTin"));
+    input.party_type = "person".to_string();
+    input.tin = "0123456789".to_string();
+    input.registration_number = "BRN-12345".to_string();
+
+    let result = use_case.execute(&pool, input).await;
+
+    let Err(AppError::Validation(err)) = result else {
+        panic!("expected validation error, got {:?}", result);
+    };
+    let fields: Vec<_> = err.fields.iter().map(|f| f.field.as_str()).collect();
+    assert_eq!(fields, ["tin", "registrationNumber"]);
+}
+
+#[tokio::test]
+async fn create_company_with_registration_number_is_allowed() {
+    let pool = get_test_pool().await;
+    let use_case = CreatePartyUseCase::new(repo());
+    let mut input = minimal_input()(&unique_name("CompanyBrn"));
+    input.registration_number = "BRN-12345".to_string();
+
+    let party = use_case.execute(&pool, input).await.unwrap();
+
+    assert_eq!(party.registration_number().unwrap().value(), "BRN-12345");
+}
+
 // =============================================================================
 // GetPartyUseCase Tests
 // =============================================================================
@@ -143,7 +258,7 @@ async fn get_party_returns_created_party() {
 
     // Get
     let get_use_case = GetPartyUseCase::new(repo());
-    let result = get_use_case.execute(&pool, party.id()).await;
+    let result = get_use_case.execute(&pool, party.id(), false).await;
 
     assert!(result.is_ok());
     assert_eq!(result.unwrap().display_name().value(), name);
@@ -154,7 +269,7 @@ async fn get_party_returns_not_found() {
     let pool = get_test_pool().await;
     let use_case = GetPartyUseCase::new(repo());
 
-    let result = use_case.execute(&pool, uuid::Uuid::now_v7()).await;
+    let result = use_case.execute(&pool, uuid::Uuid::now_v7(), false).await;
 
     assert!(matches!(result, Err(AppError::NotFound(_))));
 }
@@ -176,7 +291,14 @@ async fn list_parties_returns_data() {
 
     // List
     let list_use_case = ListPartiesUseCase::new(repo());
-    let (parties, pagination) = list_use_case.execute(&pool, 1, 10).await.unwrap();
+    let ListResult {
+        items: parties,
+        pagination,
+        ..
+    } = list_use_case
+        .execute(&pool, Pagination::new(1, 10), PartyFilter::default())
+        .await
+        .unwrap();
 
     assert!(!parties.is_empty());
     assert!(pagination.total >= 1);
@@ -187,7 +309,14 @@ async fn list_parties_pagination() {
     let pool = get_test_pool().await;
     let list_use_case = ListPartiesUseCase::new(repo());
 
-    let (parties, pagination) = list_use_case.execute(&pool, 1, 5).await.unwrap();
+    let ListResult {
+        items: parties,
+        pagination,
+        ..
+    } = list_use_case
+        .execute(&pool, Pagination::new(1, 5), PartyFilter::default())
+        .await
+        .unwrap();
 
     assert!(parties.len() <= 5);
     assert_eq!(pagination.page, 1);
@@ -0,0 +1,40 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// Source of the current time for entity timestamps
+///
+/// Entities take a clock wherever they stamp `created_at`/`updated_at`, so
+/// tests can use [`FixedClock`] instead of sleeping to observe changes.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Wall-clock time; the default everywhere outside tests
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Always returns the same instant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedClock(DateTime<Utc>);
+
+impl FixedClock {
+    pub fn new(at: DateTime<Utc>) -> Self {
+        Self(at)
+    }
+
+    /// A new clock fixed `by` later than this one
+    pub fn advanced_by(&self, by: Duration) -> Self {
+        Self(self.0 + by)
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
@@ -0,0 +1,102 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Domain enum exposed to clients as a picklist
+///
+/// Implementors are listed in [`registry`], so new variants appear in the
+/// enum options endpoint as soon as they are added to `VARIANTS`.
+pub trait EnumOptions: Copy + 'static {
+    /// Registry key, matching the snake_case field name clients see
+    const NAME: &'static str;
+
+    /// Every variant, in display order
+    const VARIANTS: &'static [Self];
+
+    /// Serialized value, as sent and accepted by the API
+    fn value(&self) -> &'static str;
+
+    /// Human-readable label
+    fn label(&self) -> &'static str;
+
+    /// All variants as value/label pairs
+    fn options() -> Vec<EnumOption> {
+        Self::VARIANTS.iter().map(EnumOption::of).collect()
+    }
+}
+
+/// A single picklist entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct EnumOption {
+    #[schema(example = "company")]
+    pub value: &'static str,
+    #[schema(example = "Company")]
+    pub label: &'static str,
+}
+
+impl EnumOption {
+    pub fn of<E: EnumOptions>(variant: &E) -> Self {
+        Self {
+            value: variant.value(),
+            label: variant.label(),
+        }
+    }
+}
+
+/// A named enum with its options
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct EnumDescriptor {
+    #[schema(example = "party_type")]
+    pub name: &'static str,
+    pub options: Vec<EnumOption>,
+}
+
+impl EnumDescriptor {
+    pub fn of<E: EnumOptions>() -> Self {
+        Self {
+            name: E::NAME,
+            options: E::options(),
+        }
+    }
+}
+
+/// Central registry of every enum exposed to clients
+pub fn registry() -> Vec<EnumDescriptor> {
+    vec![EnumDescriptor::of::<crate::party::PartyType>()]
+}
+
+// =============================================================================
+// Unit Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::party::PartyType;
+
+    #[test]
+    fn registry_contains_party_type() {
+        let registry = registry();
+
+        let party_type = registry.iter().find(|e| e.name == "party_type").unwrap();
+        let values: Vec<_> = party_type.options.iter().map(|o| o.value).collect();
+        assert_eq!(values, ["company", "person"]);
+    }
+
+    #[test]
+    fn registry_names_are_unique() {
+        let registry = registry();
+        let mut names: Vec<_> = registry.iter().map(|e| e.name).collect();
+        names.sort_unstable();
+        names.dedup();
+
+        assert_eq!(names.len(), registry.len());
+    }
+
+    #[test]
+    fn option_values_match_serde() {
+        for variant in PartyType::VARIANTS {
+            let serialized = serde_json::to_value(variant).unwrap();
+            assert_eq!(serialized, variant.value());
+        }
+    }
+}
@@ -1,3 +1,6 @@
+pub mod clock;
+pub mod enums;
+
 pub mod party {
     pub mod entity;
     pub mod value_objects;
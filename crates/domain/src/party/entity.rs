@@ -1,10 +1,16 @@
 use super::value_objects::{DisplayName, LegalName, PartyType, RegistrationNumber, Tin};
+use crate::clock::Clock;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use shared::AppError;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
 /// Party aggregate root - unified entity for companies and persons
+///
+/// Optional value objects deliberately have no `skip_serializing_if`: an
+/// absent value is always emitted as `null`, never omitted or sent as `""`,
+/// so clients see the same set of keys for every party.
 #[derive(Debug, Clone, PartialEq, ToSchema, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Party {
@@ -38,8 +44,8 @@ pub struct Party {
 
 impl Party {
     /// Create a new party with minimal required fields
-    pub fn new(party_type: PartyType, display_name: DisplayName) -> Self {
-        let now = Utc::now();
+    pub fn new(party_type: PartyType, display_name: DisplayName, clock: &impl Clock) -> Self {
+        let now = clock.now();
         Self {
             id: Uuid::now_v7(),
             party_type,
@@ -79,6 +85,41 @@ impl Party {
         }
     }
 
+    /// [`Party::from_storage`] that rejects rows no code path can produce
+    ///
+    /// An `updated_at` before `created_at` means the stored row is corrupt,
+    /// so this is an internal error rather than a client one.
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_from_storage(
+        id: Uuid,
+        party_type: PartyType,
+        display_name: DisplayName,
+        legal_name: Option<LegalName>,
+        tin: Option<Tin>,
+        registration_number: Option<RegistrationNumber>,
+        is_active: bool,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Result<Self, AppError> {
+        if updated_at < created_at {
+            return Err(AppError::Internal(format!(
+                "Party {id} has updated_at {updated_at} before created_at {created_at}"
+            )));
+        }
+
+        Ok(Self::from_storage(
+            id,
+            party_type,
+            display_name,
+            legal_name,
+            tin,
+            registration_number,
+            is_active,
+            created_at,
+            updated_at,
+        ))
+    }
+
     // Getters
     pub fn id(&self) -> Uuid {
         self.id
@@ -117,24 +158,50 @@ impl Party {
     }
 
     // Business logic methods
-    pub fn update_display_name(&mut self, display_name: DisplayName) {
+    pub fn update_display_name(&mut self, display_name: DisplayName, clock: &impl Clock) {
         self.display_name = display_name;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
     }
 
-    pub fn update_legal_name(&mut self, legal_name: Option<LegalName>) {
+    pub fn update_legal_name(&mut self, legal_name: Option<LegalName>, clock: &impl Clock) {
         self.legal_name = legal_name;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
     }
 
-    pub fn activate(&mut self) {
+    /// Switch the party to `party_type`, clearing fields the new type cannot carry
+    ///
+    /// A person has no TIN or registration number, so turning a company into a
+    /// person drops both. Returns the JSON names of the fields that held a
+    /// value and were cleared, so callers can warn about the lost data.
+    /// Changing to the current type is a no-op.
+    pub fn change_type(&mut self, party_type: PartyType, clock: &impl Clock) -> Vec<&'static str> {
+        if self.party_type == party_type {
+            return Vec::new();
+        }
+
+        let mut cleared = Vec::new();
+        if party_type == PartyType::Person {
+            if self.tin.take().is_some() {
+                cleared.push("tin");
+            }
+            if self.registration_number.take().is_some() {
+                cleared.push("registrationNumber");
+            }
+        }
+
+        self.party_type = party_type;
+        self.updated_at = clock.now();
+        cleared
+    }
+
+    pub fn activate(&mut self, clock: &impl Clock) {
         self.is_active = true;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
     }
 
-    pub fn deactivate(&mut self) {
+    pub fn deactivate(&mut self, clock: &impl Clock) {
         self.is_active = false;
-        self.updated_at = Utc::now();
+        self.updated_at = clock.now();
     }
 }
 
@@ -145,9 +212,50 @@ impl Party {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::{FixedClock, SystemClock};
+    use chrono::{Duration, TimeZone};
+
+    fn clock() -> FixedClock {
+        FixedClock::new(Utc.with_ymd_and_hms(2025, 1, 15, 10, 30, 0).unwrap())
+    }
 
     fn create_party(name: &str) -> Party {
-        Party::new(PartyType::Company, DisplayName::new(name).unwrap())
+        Party::new(
+            PartyType::Company,
+            DisplayName::new(name).unwrap(),
+            &clock(),
+        )
+    }
+
+    fn stored(created_at: DateTime<Utc>, updated_at: DateTime<Utc>) -> Result<Party, AppError> {
+        Party::try_from_storage(
+            Uuid::now_v7(),
+            PartyType::Company,
+            DisplayName::new("Stored Corp").unwrap(),
+            None,
+            None,
+            None,
+            true,
+            created_at,
+            updated_at,
+        )
+    }
+
+    #[test]
+    fn try_from_storage_accepts_ordered_timestamps() {
+        let created = clock().now();
+
+        assert!(stored(created, created).is_ok());
+        assert!(stored(created, created + Duration::seconds(1)).is_ok());
+    }
+
+    #[test]
+    fn try_from_storage_rejects_updated_before_created() {
+        let created = clock().now();
+
+        let result = stored(created, created - Duration::seconds(1));
+
+        assert!(matches!(result, Err(AppError::Internal(_))));
     }
 
     #[test]
@@ -170,9 +278,21 @@ mod tests {
     }
 
     #[test]
-    fn new_party_has_timestamps() {
-        let before = Utc::now();
+    fn new_party_has_timestamps_from_clock() {
         let party = create_party("Test Corp");
+
+        assert_eq!(party.created_at(), clock().now());
+        assert_eq!(party.updated_at(), clock().now());
+    }
+
+    #[test]
+    fn new_party_with_system_clock_uses_current_time() {
+        let before = Utc::now();
+        let party = Party::new(
+            PartyType::Company,
+            DisplayName::new("Test Corp").unwrap(),
+            &SystemClock,
+        );
         let after = Utc::now();
 
         assert!(party.created_at() >= before);
@@ -183,41 +303,131 @@ mod tests {
     #[test]
     fn update_display_name_changes_name_and_timestamp() {
         let mut party = create_party("Old Name");
-        let before_update = party.updated_at();
+        let later = clock().advanced_by(Duration::minutes(5));
 
-        std::thread::sleep(std::time::Duration::from_millis(1));
-        party.update_display_name(DisplayName::new("New Name").unwrap());
+        party.update_display_name(DisplayName::new("New Name").unwrap(), &later);
 
         assert_eq!(party.display_name().value(), "New Name");
-        assert!(party.updated_at() > before_update);
+        assert_eq!(party.updated_at(), later.now());
+        assert_eq!(party.created_at(), clock().now());
+    }
+
+    #[test]
+    fn update_legal_name_sets_and_clears() {
+        let mut party = create_party("Test Corp");
+        let later = clock().advanced_by(Duration::seconds(1));
+
+        party.update_legal_name(Some(LegalName::new("Test Corp Ltd.").unwrap()), &later);
+        assert_eq!(party.legal_name().unwrap().value(), "Test Corp Ltd.");
+        assert_eq!(party.updated_at(), later.now());
+
+        party.update_legal_name(None, &later);
+        assert!(party.legal_name().is_none());
+    }
+
+    #[test]
+    fn change_type_to_person_clears_company_fields() {
+        let mut party = Party::from_storage(
+            Uuid::now_v7(),
+            PartyType::Company,
+            DisplayName::new("Test Corp").unwrap(),
+            None,
+            Some(Tin::new("0123456789").unwrap()),
+            Some(RegistrationNumber::new("BRN-12345").unwrap()),
+            true,
+            clock().now(),
+            clock().now(),
+        );
+        let later = clock().advanced_by(Duration::minutes(1));
+
+        let cleared = party.change_type(PartyType::Person, &later);
+
+        assert_eq!(cleared, vec!["tin", "registrationNumber"]);
+        assert_eq!(party.party_type(), PartyType::Person);
+        assert!(party.tin().is_none());
+        assert!(party.registration_number().is_none());
+        assert_eq!(party.updated_at(), later.now());
+    }
+
+    #[test]
+    fn change_type_to_company_keeps_fields() {
+        let mut party = Party::new(
+            PartyType::Person,
+            DisplayName::new("Jane Doe").unwrap(),
+            &clock(),
+        );
+
+        let cleared = party.change_type(PartyType::Company, &clock());
+
+        assert!(cleared.is_empty());
+        assert_eq!(party.party_type(), PartyType::Company);
+    }
+
+    #[test]
+    fn change_type_to_same_type_is_noop() {
+        let mut party = create_party("Test Corp");
+        let later = clock().advanced_by(Duration::minutes(1));
+
+        let cleared = party.change_type(PartyType::Company, &later);
+
+        assert!(cleared.is_empty());
+        assert_eq!(party.updated_at(), clock().now());
     }
 
     #[test]
     fn deactivate_sets_is_active_false() {
         let mut party = create_party("Test Corp");
         assert!(party.is_active());
+        let later = clock().advanced_by(Duration::hours(1));
 
-        party.deactivate();
+        party.deactivate(&later);
 
         assert!(!party.is_active());
+        assert_eq!(party.updated_at(), later.now());
     }
 
     #[test]
     fn activate_sets_is_active_true() {
         let mut party = create_party("Test Corp");
-        party.deactivate();
+        party.deactivate(&clock());
         assert!(!party.is_active());
 
-        party.activate();
+        party.activate(&clock());
 
         assert!(party.is_active());
     }
 
     #[test]
     fn can_create_person_party() {
-        let party = Party::new(PartyType::Person, DisplayName::new("John Doe").unwrap());
+        let party = Party::new(
+            PartyType::Person,
+            DisplayName::new("John Doe").unwrap(),
+            &clock(),
+        );
 
         assert_eq!(party.party_type(), PartyType::Person);
         assert_eq!(party.display_name().value(), "John Doe");
     }
+
+    #[test]
+    fn absent_optional_fields_serialize_as_null() {
+        let party = create_party("Test Corp");
+
+        let json = serde_json::to_value(&party).unwrap();
+
+        for field in ["legalName", "tin", "registrationNumber"] {
+            assert!(json.get(field).is_some(), "{field} must not be omitted");
+            assert!(json[field].is_null(), "{field} must be null");
+        }
+    }
+
+    #[test]
+    fn present_optional_fields_serialize_as_strings() {
+        let mut party = create_party("Test Corp");
+        party.update_legal_name(Some(LegalName::new("Test Corp Ltd.").unwrap()), &clock());
+
+        let json = serde_json::to_value(&party).unwrap();
+
+        assert_eq!(json["legalName"], "Test Corp Ltd.");
+    }
 }
@@ -1,6 +1,8 @@
+use crate::enums::EnumOptions;
 use derive_more::{AsRef, Deref, Display};
 use serde::{Deserialize, Serialize};
 use shared::DomainError;
+use std::str::FromStr;
 use utoipa::ToSchema;
 
 /// Party type enum - company or person
@@ -18,8 +20,34 @@ impl PartyType {
             PartyType::Person => "person",
         }
     }
+}
+
+impl EnumOptions for PartyType {
+    const NAME: &'static str = "party_type";
+    const VARIANTS: &'static [Self] = &[PartyType::Company, PartyType::Person];
+
+    fn value(&self) -> &'static str {
+        self.as_str()
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            PartyType::Company => "Company",
+            PartyType::Person => "Person",
+        }
+    }
+}
+
+impl FromStr for PartyType {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(DomainError::InvalidValue(
+                "Party type is required. Must be 'company' or 'person'".to_string(),
+            ));
+        }
 
-    pub fn from_str(s: &str) -> Result<Self, DomainError> {
         match s.to_lowercase().as_str() {
             "company" => Ok(PartyType::Company),
             "person" => Ok(PartyType::Person),
@@ -37,6 +65,11 @@ impl std::fmt::Display for PartyType {
     }
 }
 
+/// Trim a name and collapse internal whitespace runs to a single space
+fn normalize_name(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
 /// Display name with validation (required field)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Display, AsRef, Deref, ToSchema)]
 #[schema(value_type = String, example = "Acme Corporation")]
@@ -44,7 +77,7 @@ pub struct DisplayName(String);
 
 impl DisplayName {
     pub fn new(name: impl Into<String>) -> Result<Self, DomainError> {
-        let name = name.into().trim().to_string();
+        let name = normalize_name(&name.into());
         if name.is_empty() {
             return Err(DomainError::InvalidValue(
                 "Display name cannot be empty".to_string(),
@@ -70,7 +103,7 @@ pub struct LegalName(String);
 
 impl LegalName {
     pub fn new(name: impl Into<String>) -> Result<Self, DomainError> {
-        let name = name.into().trim().to_string();
+        let name = normalize_name(&name.into());
         if name.is_empty() {
             return Err(DomainError::InvalidValue(
                 "Legal name cannot be empty".to_string(),
@@ -139,6 +172,46 @@ impl RegistrationNumber {
     }
 }
 
+/// Free-form label attached to a party, e.g. `vip` or `prospect`
+///
+/// Stored lowercased so `VIP` and `vip` are the same tag.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    Display,
+    AsRef,
+    Deref,
+    ToSchema,
+)]
+#[schema(value_type = String, example = "vip")]
+pub struct Tag(String);
+
+impl Tag {
+    pub fn new(tag: impl Into<String>) -> Result<Self, DomainError> {
+        let tag = tag.into().trim().to_lowercase();
+        if tag.is_empty() {
+            return Err(DomainError::InvalidValue("Tag cannot be empty".to_string()));
+        }
+        if tag.chars().count() > 50 {
+            return Err(DomainError::InvalidValue(
+                "Tag too long (max 50 chars)".to_string(),
+            ));
+        }
+        Ok(Self(tag))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
 // =============================================================================
 // Unit Tests
 // =============================================================================
@@ -170,6 +243,15 @@ mod tests {
             assert!(PartyType::from_str("").is_err());
         }
 
+        #[test]
+        fn from_str_rejects_blank_listing_allowed_values() {
+            for blank in ["", "   "] {
+                let err = PartyType::from_str(blank).unwrap_err();
+                assert!(err.message().contains("required"));
+                assert!(err.message().contains("'company' or 'person'"));
+            }
+        }
+
         #[test]
         fn as_str_returns_lowercase() {
             assert_eq!(PartyType::Company.as_str(), "company");
@@ -192,6 +274,15 @@ mod tests {
             assert_eq!(name.value(), "Acme Corp");
         }
 
+        #[test]
+        fn collapses_internal_whitespace() {
+            let name = DisplayName::new("Acme   Corp").unwrap();
+            assert_eq!(name.value(), "Acme Corp");
+
+            let name = DisplayName::new(" Acme \t\n Corp ").unwrap();
+            assert_eq!(name.value(), "Acme Corp");
+        }
+
         #[test]
         fn rejects_empty_name() {
             assert!(DisplayName::new("").is_err());
@@ -226,6 +317,12 @@ mod tests {
             assert_eq!(name.value(), "Acme Corp");
         }
 
+        #[test]
+        fn collapses_internal_whitespace() {
+            let name = LegalName::new("Acme   Corporation  Ltd.").unwrap();
+            assert_eq!(name.value(), "Acme Corporation Ltd.");
+        }
+
         #[test]
         fn rejects_empty_name() {
             assert!(LegalName::new("").is_err());
@@ -288,4 +385,31 @@ mod tests {
             assert!(RegistrationNumber::new(long_num).is_err());
         }
     }
+
+    mod tag {
+        use super::*;
+
+        #[test]
+        fn lowercases_and_trims() {
+            let tag = Tag::new("  VIP ").unwrap();
+            assert_eq!(tag.value(), "vip");
+        }
+
+        #[test]
+        fn rejects_empty_tag() {
+            assert!(Tag::new("").is_err());
+            assert!(Tag::new("   ").is_err());
+        }
+
+        #[test]
+        fn rejects_too_long_tag() {
+            assert!(Tag::new("a".repeat(51)).is_err());
+        }
+
+        #[test]
+        fn counts_characters_not_bytes() {
+            assert!(Tag::new("đ".repeat(50)).is_ok());
+            assert!(Tag::new("đ".repeat(51)).is_err());
+        }
+    }
 }
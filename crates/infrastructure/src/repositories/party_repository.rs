@@ -1,17 +1,62 @@
-use application::ports::PartyRepository;
+use application::ports::{PartyChanges, PartyFilter, PartyRepository, PartyStatusCounts};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use domain::party::Party;
-use domain::party::value_objects::{DisplayName, LegalName, PartyType, RegistrationNumber, Tin};
-use shared::{AppError, PaginationMeta};
+use domain::party::value_objects::{
+    DisplayName, LegalName, PartyType, RegistrationNumber, Tag, Tin,
+};
+use futures_util::stream::{BoxStream, StreamExt, TryStreamExt};
+use shared::sql::contains_pattern;
+use shared::{AppError, ListResult, Pagination, PaginationMeta, Sort};
+use sqlx::{Postgres, QueryBuilder};
+use std::str::FromStr;
+use std::sync::LazyLock;
 use uuid::Uuid;
 
-#[derive(Default)]
-pub struct PartyRepositoryImpl;
+#[derive(Debug, Clone, Copy)]
+pub struct PartyRepositoryImpl {
+    default_sort: Sort,
+    case_sensitive_search: bool,
+}
 
 impl PartyRepositoryImpl {
+    /// List order when the caller does not configure one: newest first
+    pub const DEFAULT_SORT: Sort = Sort::desc("created_at");
+
+    /// Columns a list may be ordered by
+    pub const SORTABLE_FIELDS: &'static [&'static str] = &[
+        "created_at",
+        "updated_at",
+        "display_name",
+        "legal_name",
+        "party_type",
+    ];
+
     pub fn new() -> Self {
-        Self
+        Self::with_default_sort(Self::DEFAULT_SORT)
+    }
+
+    /// Repository listing in `sort` order instead of [`Self::DEFAULT_SORT`]
+    pub fn with_default_sort(sort: Sort) -> Self {
+        Self {
+            default_sort: sort,
+            case_sensitive_search: false,
+        }
+    }
+
+    /// Match `PartyFilter::search` case-sensitively (`LIKE`) instead of with `ILIKE`
+    ///
+    /// Full-text matching folds case, so a case-sensitive search skips it and
+    /// compares names and TIN as substrings only.
+    pub fn with_case_sensitive_search(mut self, enabled: bool) -> Self {
+        self.case_sensitive_search = enabled;
+        self
+    }
+}
+
+impl Default for PartyRepositoryImpl {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -23,6 +68,11 @@ const INSERT_FIELDS: &str = "id, party_type, display_name, legal_name, tin, \
 const SELECT_FIELDS: &str = "id, party_type::text as party_type, display_name, legal_name, tin, \
                              registration_number, is_active, created_at, updated_at";
 
+// Full export in insertion order; `id` keeps ties deterministic
+static STREAM_ALL_SQL: LazyLock<String> = LazyLock::new(|| {
+    format!("SELECT {SELECT_FIELDS} FROM party WHERE deleted_at IS NULL ORDER BY created_at, id")
+});
+
 // Private row struct for database deserialization
 #[derive(sqlx::FromRow)]
 struct PartyRow {
@@ -37,9 +87,56 @@ struct PartyRow {
     updated_at: DateTime<Utc>,
 }
 
+/// Append the WHERE clause for `filter`, shared by the SELECT and COUNT queries
+fn push_filter(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    filter: &PartyFilter,
+    case_sensitive_search: bool,
+) {
+    builder.push(" WHERE deleted_at IS NULL");
+
+    if let Some(is_active) = filter.is_active {
+        builder.push(" AND is_active = ").push_bind(is_active);
+    }
+
+    if let Some(search) = &filter.search {
+        let pattern = contains_pattern(search);
+        if case_sensitive_search {
+            builder
+                .push(" AND (display_name LIKE ")
+                .push_bind(pattern.clone())
+                .push(" ESCAPE '\\' OR legal_name LIKE ")
+                .push_bind(pattern.clone())
+                .push(" ESCAPE '\\' OR tin LIKE ")
+                .push_bind(pattern)
+                .push(" ESCAPE '\\')");
+        } else {
+            // Whole words hit the tsvector GIN index and substrings the pg_trgm
+            // ones, so every OR arm is indexed and the planner can BitmapOr them
+            builder
+                .push(" AND (search_vector @@ plainto_tsquery('simple', ")
+                .push_bind(search.clone())
+                .push(") OR display_name ILIKE ")
+                .push_bind(pattern.clone())
+                .push(" ESCAPE '\\' OR legal_name ILIKE ")
+                .push_bind(pattern.clone())
+                .push(" ESCAPE '\\' OR tin ILIKE ")
+                .push_bind(pattern)
+                .push(" ESCAPE '\\')");
+        }
+    }
+
+    if let Some(tag) = &filter.tag {
+        builder
+            .push(" AND EXISTS (SELECT 1 FROM party_tag t WHERE t.party_id = party.id AND t.tag = ")
+            .push_bind(tag.value().to_string())
+            .push(")");
+    }
+}
+
 impl PartyRow {
-    fn to_domain(self) -> Result<Party, AppError> {
-        Ok(Party::from_storage(
+    fn into_domain(self) -> Result<Party, AppError> {
+        Party::try_from_storage(
             self.id,
             PartyType::from_str(&self.party_type)?,
             DisplayName::new(self.display_name)?,
@@ -51,7 +148,7 @@ impl PartyRow {
             self.is_active,
             self.created_at,
             self.updated_at,
-        ))
+        )
     }
 }
 
@@ -88,7 +185,7 @@ impl PartyRepository for PartyRepositoryImpl {
             "UPDATE party SET \
              party_type = $2::party_type, display_name = $3, legal_name = $4, tin = $5, \
              registration_number = $6, is_active = $7, updated_at = $8 \
-             WHERE id = $1",
+             WHERE id = $1 AND deleted_at IS NULL",
         )
         .bind(party.id())
         .bind(party.party_type().as_str())
@@ -104,57 +201,235 @@ impl PartyRepository for PartyRepositoryImpl {
         Ok(())
     }
 
-    async fn find_by_id<'a, E>(&self, executor: E, id: Uuid) -> Result<Option<Party>, AppError>
+    async fn bulk_update<'a, E>(
+        &self,
+        executor: E,
+        ids: &[Uuid],
+        changes: &PartyChanges,
+        updated_at: DateTime<Utc>,
+    ) -> Result<u64, AppError>
     where
         E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
     {
-        sqlx::query_as::<_, PartyRow>(&format!("SELECT {SELECT_FIELDS} FROM party WHERE id = $1"))
-            .bind(id)
-            .fetch_optional(&mut *executor.acquire().await?)
-            .await?
-            .map(|row| row.to_domain())
-            .transpose()
+        let mut query = QueryBuilder::<Postgres>::new("UPDATE party SET updated_at = ");
+        query.push_bind(updated_at);
+        if let Some(is_active) = changes.is_active {
+            query.push(", is_active = ").push_bind(is_active);
+        }
+        if let Some(party_type) = changes.party_type {
+            query
+                .push(", party_type = ")
+                .push_bind(party_type.as_str())
+                .push("::party_type");
+            // Same rule as `Party::change_type`: persons carry no company identifiers
+            if party_type == PartyType::Person {
+                query.push(", tin = NULL, registration_number = NULL");
+            }
+        }
+        query
+            .push(" WHERE id = ANY(")
+            .push_bind(ids.to_vec())
+            .push(") AND deleted_at IS NULL");
+
+        let result = query
+            .build()
+            .execute(&mut *executor.acquire().await?)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn find_by_id<'a, E>(
+        &self,
+        executor: E,
+        id: Uuid,
+        include_deleted: bool,
+    ) -> Result<Option<Party>, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        sqlx::query_as::<_, PartyRow>(&format!(
+            "SELECT {SELECT_FIELDS} FROM party \
+             WHERE id = $1 AND (deleted_at IS NULL OR $2)"
+        ))
+        .bind(id)
+        .bind(include_deleted)
+        .fetch_optional(&mut *executor.acquire().await?)
+        .await?
+        .map(|row| row.into_domain())
+        .transpose()
+    }
+
+    async fn find_by_tin<'a, E>(&self, executor: E, tin: &Tin) -> Result<Option<Party>, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        sqlx::query_as::<_, PartyRow>(&format!(
+            "SELECT {SELECT_FIELDS} FROM party \
+             WHERE tin = $1 AND deleted_at IS NULL ORDER BY created_at, id LIMIT 1"
+        ))
+        .bind(tin.value())
+        .fetch_optional(&mut *executor.acquire().await?)
+        .await?
+        .map(|row| row.into_domain())
+        .transpose()
+    }
+
+    async fn lock_tin<'a, E>(&self, executor: E, tin: &Tin) -> Result<(), AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended('party:tin:' || $1, 0))")
+            .bind(tin.value())
+            .execute(&mut *executor.acquire().await?)
+            .await?;
+
+        Ok(())
     }
 
     async fn find_paginated<'a, E>(
         &self,
         executor: E,
-        page: u32,
-        page_size: u32,
-    ) -> Result<(Vec<Party>, PaginationMeta), AppError>
+        pagination: Pagination,
+        filter: &PartyFilter,
+    ) -> Result<ListResult<Party>, AppError>
     where
         E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
     {
         let mut conn = executor.acquire().await?;
 
         // Get total count
-        let (total,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM party")
-            .fetch_one(&mut *conn)
-            .await?;
+        let mut count_query = QueryBuilder::new("SELECT COUNT(*) FROM party");
+        push_filter(&mut count_query, filter, self.case_sensitive_search);
+        let (total,): (i64,) = count_query.build_query_as().fetch_one(&mut *conn).await?;
 
         // Get paginated results
-        let offset = u64::from(page.saturating_sub(1)) * u64::from(page_size);
-        let parties: Vec<Party> = sqlx::query_as::<_, PartyRow>(&format!(
-            "SELECT {SELECT_FIELDS} FROM party \
-             ORDER BY created_at DESC LIMIT $1 OFFSET $2"
+        // Clamp the offset so a huge page can't wrap into a negative bind; it
+        // simply lands past the last row and yields an empty page
+        let offset = i64::try_from(pagination.offset()).unwrap_or(i64::MAX);
+        let mut select_query = QueryBuilder::new(format!("SELECT {SELECT_FIELDS} FROM party"));
+        push_filter(&mut select_query, filter, self.case_sensitive_search);
+        select_query.push(" ORDER BY ");
+        // Best matches first when searching, unless the caller chose an order;
+        // substring-only matches rank 0 and fall back to the default sort
+        if let (None, Some(search)) = (filter.sort, &filter.search) {
+            select_query
+                .push("ts_rank(search_vector, plainto_tsquery('simple', ")
+                .push_bind(search.clone())
+                .push(")) DESC, ");
+        }
+        // Sort fields come from SORTABLE_FIELDS, never from user input; id
+        // breaks ties so pages stay stable
+        let sort = filter.sort.unwrap_or(self.default_sort);
+        select_query
+            .push(format!("{sort}, id {} LIMIT ", sort.direction.as_sql()))
+            .push_bind(i64::from(pagination.page_size()))
+            .push(" OFFSET ")
+            .push_bind(offset);
+
+        let parties: Vec<Party> = select_query
+            .build_query_as::<PartyRow>()
+            .fetch_all(&mut *conn)
+            .await?
+            .into_iter()
+            .map(|row| row.into_domain())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // COUNT(*) is never negative
+        let total = u64::try_from(total).unwrap_or_default();
+        Ok(ListResult::new(
+            parties,
+            PaginationMeta::for_page(pagination, total),
         ))
-        .bind(page_size as i64)
-        .bind(offset as i64)
-        .fetch_all(&mut *conn)
-        .await?
-        .into_iter()
-        .map(|row| row.to_domain())
-        .collect::<Result<Vec<_>, _>>()?;
+    }
+
+    async fn count_by_status<'a, E>(&self, executor: E) -> Result<PartyStatusCounts, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        let (active, inactive): (i64, i64) = sqlx::query_as(
+            "SELECT COUNT(*) FILTER (WHERE is_active), COUNT(*) FILTER (WHERE NOT is_active) \
+             FROM party WHERE deleted_at IS NULL",
+        )
+        .fetch_one(&mut *executor.acquire().await?)
+        .await?;
+
+        // COUNT(*) is never negative
+        Ok(PartyStatusCounts {
+            active: u64::try_from(active).unwrap_or_default(),
+            inactive: u64::try_from(inactive).unwrap_or_default(),
+        })
+    }
+
+    fn stream_all<'a>(&'a self, pool: &'a sqlx::PgPool) -> BoxStream<'a, Result<Party, AppError>> {
+        sqlx::query_as::<_, PartyRow>(STREAM_ALL_SQL.as_str())
+            .fetch(pool)
+            .map_err(AppError::from)
+            .and_then(|row| async move { row.into_domain() })
+            .boxed()
+    }
+
+    async fn add_tags<'a, E>(
+        &self,
+        executor: E,
+        party_id: Uuid,
+        tags: &[Tag],
+    ) -> Result<(), AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        let tags: Vec<&str> = tags.iter().map(|t| t.value()).collect();
+        sqlx::query(
+            "INSERT INTO party_tag (party_id, tag) SELECT $1, UNNEST($2::text[]) \
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(party_id)
+        .bind(tags)
+        .execute(&mut *executor.acquire().await?)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn remove_tag<'a, E>(
+        &self,
+        executor: E,
+        party_id: Uuid,
+        tag: &Tag,
+    ) -> Result<(), AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        sqlx::query("DELETE FROM party_tag WHERE party_id = $1 AND tag = $2")
+            .bind(party_id)
+            .bind(tag.value())
+            .execute(&mut *executor.acquire().await?)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn find_tags<'a, E>(&self, executor: E, party_id: Uuid) -> Result<Vec<Tag>, AppError>
+    where
+        E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
+    {
+        let tags: Vec<String> =
+            sqlx::query_scalar("SELECT tag FROM party_tag WHERE party_id = $1 ORDER BY tag")
+                .bind(party_id)
+                .fetch_all(&mut *executor.acquire().await?)
+                .await?;
 
-        let total_u32 = total.try_into().unwrap_or(u32::MAX);
-        Ok((parties, PaginationMeta::new(page, page_size, total_u32)))
+        tags.into_iter()
+            .map(|tag| Tag::new(tag).map_err(AppError::from))
+            .collect()
     }
 
     async fn delete<'a, E>(&self, executor: E, id: Uuid) -> Result<(), AppError>
     where
         E: sqlx::Acquire<'a, Database = sqlx::Postgres> + Send,
     {
-        sqlx::query("DELETE FROM party WHERE id = $1")
+        // Soft delete: keep the row, leave is_active as the business state
+        sqlx::query("UPDATE party SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL")
             .bind(id)
             .execute(&mut *executor.acquire().await?)
             .await?;
@@ -0,0 +1,29 @@
+use shared::PaginationMeta;
+
+// ============================================================================
+// Pagination
+// ============================================================================
+
+/// Assert the invariants `PaginationMeta::new` promises, whatever the page
+///
+/// An empty result still reports one page, so `total_pages` is never zero.
+pub fn assert_pagination_consistent(meta: &PaginationMeta) {
+    assert!(meta.page >= 1, "page is 1-based: {meta:?}");
+    assert!(meta.page_size >= 1, "page_size is never zero: {meta:?}");
+
+    let expected_pages = meta.total.div_ceil(u64::from(meta.page_size)).max(1);
+    assert_eq!(
+        meta.total_pages, expected_pages,
+        "total_pages must be ceil(total / page_size): {meta:?}"
+    );
+    assert_eq!(
+        meta.has_next,
+        u64::from(meta.page) < meta.total_pages,
+        "has_next must hold exactly when page < total_pages: {meta:?}"
+    );
+    assert_eq!(
+        meta.has_prev,
+        meta.page > 1,
+        "has_prev must hold exactly when page > 1: {meta:?}"
+    );
+}
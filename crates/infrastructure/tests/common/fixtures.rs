@@ -1,9 +1,7 @@
 use application::ports::PartyRepository;
+use domain::clock::SystemClock;
 use domain::party::{DisplayName, LegalName, Party, PartyType, RegistrationNumber, Tin};
-use fake::{
-    Fake,
-    faker::company::en::CompanyName,
-};
+use fake::{Fake, faker::company::en::CompanyName};
 use infrastructure::repositories::PartyRepositoryImpl;
 use sqlx::PgPool;
 
@@ -22,7 +20,11 @@ pub fn unique_name(prefix: &str) -> String {
 
 /// Create party with specific name
 pub fn party(name: &str) -> Party {
-    Party::new(PartyType::Company, DisplayName::new(name).unwrap())
+    Party::new(
+        PartyType::Company,
+        DisplayName::new(name).unwrap(),
+        &SystemClock,
+    )
 }
 
 /// Create party with unique fake company name
@@ -52,11 +54,7 @@ pub fn fake_party_full() -> Party {
 // ============================================================================
 
 /// Seed n fake parties, returns them for assertions
-pub async fn seed_n(
-    pool: &PgPool,
-    repo: &PartyRepositoryImpl,
-    n: usize,
-) -> Vec<Party> {
+pub async fn seed_n(pool: &PgPool, repo: &PartyRepositoryImpl, n: usize) -> Vec<Party> {
     let mut parties = Vec::with_capacity(n);
     for _ in 0..n {
         let p = fake_party();
@@ -74,10 +72,7 @@ pub async fn seed_one(pool: &PgPool, repo: &PartyRepositoryImpl) -> Party {
 }
 
 /// Seed predefined parties with unique names
-pub async fn seed_known(
-    pool: &PgPool,
-    repo: &PartyRepositoryImpl,
-) -> (Party, Party, Party) {
+pub async fn seed_known(pool: &PgPool, repo: &PartyRepositoryImpl) -> (Party, Party, Party) {
     let acme = party(&unique_name("Acme"));
     let wayne = party(&unique_name("Wayne"));
     let stark = party(&unique_name("Stark"));
@@ -1,16 +1,22 @@
 //! Repository tests for Party
 //!
-//! Uses shared test database with #[tokio::test].
+//! Most tests use the shared test database with #[tokio::test] and unique
+//! names. Tests asserting exact counts use #[sqlx::test], which gives each
+//! test its own freshly migrated database.
 
 mod common;
 
-use application::ports::PartyRepository;
+use application::ports::{PartyChanges, PartyFilter, PartyRepository};
 use common::{
     PartyRepositoryImpl,
-    fixtures::{fake_party, fake_party_full, seed_known, seed_n, seed_one},
+    assertions::assert_pagination_consistent,
+    fixtures::{fake_party, fake_party_full, party, seed_known, seed_n, seed_one},
     get_test_pool,
 };
-use domain::party::DisplayName;
+use domain::clock::{FixedClock, SystemClock};
+use domain::party::{DisplayName, LegalName, Party, PartyType, Tin};
+use shared::{AppError, DomainError, ListResult, Pagination, Sort};
+use sqlx::PgPool;
 
 // ============================================================================
 // CRUD Tests
@@ -24,7 +30,11 @@ async fn create_and_find() {
     let party = fake_party();
     repo.create(&pool, &party).await.unwrap();
 
-    let found = repo.find_by_id(&pool, party.id()).await.unwrap().unwrap();
+    let found = repo
+        .find_by_id(&pool, party.id(), false)
+        .await
+        .unwrap()
+        .unwrap();
 
     assert_eq!(found.id(), party.id());
     assert_eq!(found.display_name().value(), party.display_name().value());
@@ -38,7 +48,11 @@ async fn create_with_all_fields() {
     let party = fake_party_full();
     repo.create(&pool, &party).await.unwrap();
 
-    let found = repo.find_by_id(&pool, party.id()).await.unwrap().unwrap();
+    let found = repo
+        .find_by_id(&pool, party.id(), false)
+        .await
+        .unwrap()
+        .unwrap();
 
     assert!(found.legal_name().is_some());
     assert!(found.tin().is_some());
@@ -46,12 +60,32 @@ async fn create_with_all_fields() {
     assert!(found.is_active());
 }
 
+#[tokio::test]
+async fn find_by_id_returns_inactive_party() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    let mut party = seed_one(&pool, &repo).await;
+    party.deactivate(&SystemClock);
+    repo.update(&pool, &party).await.unwrap();
+
+    let found = repo
+        .find_by_id(&pool, party.id(), false)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!found.is_active());
+}
+
 #[tokio::test]
 async fn find_nonexistent_returns_none() {
     let pool = get_test_pool().await;
     let repo = PartyRepositoryImpl::new();
 
-    let result = repo.find_by_id(&pool, uuid::Uuid::now_v7()).await.unwrap();
+    let result = repo
+        .find_by_id(&pool, uuid::Uuid::now_v7(), false)
+        .await
+        .unwrap();
 
     assert!(result.is_none());
 }
@@ -64,10 +98,14 @@ async fn update_party() {
     let mut party = seed_one(&pool, &repo).await;
     let new_name = format!("Updated_{}", uuid::Uuid::now_v7());
 
-    party.update_display_name(DisplayName::new(&new_name).unwrap());
+    party.update_display_name(DisplayName::new(&new_name).unwrap(), &SystemClock);
     repo.update(&pool, &party).await.unwrap();
 
-    let found = repo.find_by_id(&pool, party.id()).await.unwrap().unwrap();
+    let found = repo
+        .find_by_id(&pool, party.id(), false)
+        .await
+        .unwrap()
+        .unwrap();
     assert_eq!(found.display_name().value(), new_name);
 }
 
@@ -80,10 +118,63 @@ async fn delete_party() {
 
     repo.delete(&pool, party.id()).await.unwrap();
 
-    let found = repo.find_by_id(&pool, party.id()).await.unwrap();
+    let found = repo.find_by_id(&pool, party.id(), false).await.unwrap();
     assert!(found.is_none());
 }
 
+#[tokio::test]
+async fn delete_hides_party_but_keeps_is_active() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    let party = seed_one(&pool, &repo).await;
+
+    repo.delete(&pool, party.id()).await.unwrap();
+
+    assert!(
+        repo.find_by_id(&pool, party.id(), false)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    let found = repo
+        .find_by_id(&pool, party.id(), true)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.id(), party.id());
+    let (is_active, deleted): (bool, bool) =
+        sqlx::query_as("SELECT is_active, deleted_at IS NOT NULL FROM party WHERE id = $1")
+            .bind(party.id())
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert!(is_active);
+    assert!(deleted);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn deleted_parties_are_excluded_from_lists(pool: PgPool) {
+    let repo = PartyRepositoryImpl::new();
+
+    let kept = seed_one(&pool, &repo).await;
+    let deleted = seed_one(&pool, &repo).await;
+    repo.delete(&pool, deleted.id()).await.unwrap();
+
+    let ListResult {
+        items,
+        pagination: meta,
+        ..
+    } = repo
+        .find_paginated(&pool, Pagination::new(1, 10), &PartyFilter::default())
+        .await
+        .unwrap();
+
+    assert_eq!(meta.total, 1);
+    assert_eq!(items[0].id(), kept.id());
+    assert_pagination_consistent(&meta);
+}
+
 // ============================================================================
 // Query Tests
 // ============================================================================
@@ -96,34 +187,229 @@ async fn find_seeded_parties() {
     let (acme, wayne, stark) = seed_known(&pool, &repo).await;
 
     for party in [&acme, &wayne, &stark] {
-        let found = repo.find_by_id(&pool, party.id()).await.unwrap().unwrap();
+        let found = repo
+            .find_by_id(&pool, party.id(), false)
+            .await
+            .unwrap()
+            .unwrap();
         assert_eq!(found.display_name().value(), party.display_name().value());
     }
 }
 
-#[tokio::test]
-async fn pagination_basic() {
-    let pool = get_test_pool().await;
+#[sqlx::test(migrations = "../../migrations")]
+async fn pagination_basic(pool: PgPool) {
     let repo = PartyRepositoryImpl::new();
 
     seed_n(&pool, &repo, 15).await;
 
-    // Get paginated results
-    let (items, meta) = repo.find_paginated(&pool, 1, 10).await.unwrap();
+    let ListResult {
+        items,
+        pagination: meta,
+        ..
+    } = repo
+        .find_paginated(&pool, Pagination::new(1, 10), &PartyFilter::default())
+        .await
+        .unwrap();
+
+    // Isolated database: only the seeded rows exist
+    assert_eq!(items.len(), 10);
+    assert_eq!(meta.total, 15);
+    assert_eq!(meta.total_pages, 2);
+    assert!(meta.has_next);
+    assert_pagination_consistent(&meta);
+}
 
-    // Should have items (at least what we seeded)
-    assert!(!items.is_empty());
-    assert!(meta.total >= 15);
+#[sqlx::test(migrations = "../../migrations")]
+async fn pagination_page_size(pool: PgPool) {
+    let repo = PartyRepositoryImpl::new();
+
+    seed_n(&pool, &repo, 7).await;
+
+    let ListResult {
+        items,
+        pagination: meta,
+        ..
+    } = repo
+        .find_paginated(&pool, Pagination::new(2, 5), &PartyFilter::default())
+        .await
+        .unwrap();
+
+    assert_eq!(items.len(), 2);
+    assert_eq!(meta.total, 7);
+    assert!(!meta.has_next);
+    assert_pagination_consistent(&meta);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn pagination_zero_page_size_is_clamped_to_one(pool: PgPool) {
+    let repo = PartyRepositoryImpl::new();
+
+    seed_n(&pool, &repo, 3).await;
+
+    let ListResult {
+        items,
+        pagination: meta,
+        ..
+    } = repo
+        .find_paginated(&pool, Pagination::new(1, 0), &PartyFilter::default())
+        .await
+        .unwrap();
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(meta.page_size, 1);
+    assert_eq!(meta.total_pages, 3);
+    assert_pagination_consistent(&meta);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn count_by_status_splits_active_and_inactive(pool: PgPool) {
+    let repo = PartyRepositoryImpl::new();
+
+    seed_n(&pool, &repo, 2).await;
+    let mut inactive = fake_party();
+    inactive.deactivate(&SystemClock);
+    repo.create(&pool, &inactive).await.unwrap();
+
+    let counts = repo.count_by_status(&pool).await.unwrap();
+
+    assert_eq!(counts.active, 2);
+    assert_eq!(counts.inactive, 1);
+    assert_eq!(counts.total(), 3);
+}
+
+#[test]
+fn default_sort_is_newest_first() {
+    assert_eq!(PartyRepositoryImpl::DEFAULT_SORT, Sort::desc("created_at"));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn lists_in_default_sort_order(pool: PgPool) {
+    let repo = PartyRepositoryImpl::new();
+
+    let seeded = seed_n(&pool, &repo, 3).await;
+
+    let ListResult { items, .. } = repo
+        .find_paginated(&pool, Pagination::new(1, 10), &PartyFilter::default())
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = items.iter().map(|p| p.id()).collect();
+    let newest_first: Vec<_> = seeded.iter().rev().map(|p| p.id()).collect();
+    assert_eq!(ids, newest_first);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn configured_default_sort_overrides_order(pool: PgPool) {
+    let repo = PartyRepositoryImpl::with_default_sort(Sort::asc("display_name"));
+
+    for name in ["Charlie", "Alpha", "Bravo"] {
+        repo.create(&pool, &party(name)).await.unwrap();
+    }
+
+    let ListResult { items, .. } = repo
+        .find_paginated(&pool, Pagination::new(1, 10), &PartyFilter::default())
+        .await
+        .unwrap();
+
+    let names: Vec<_> = items.iter().map(|p| p.display_name().value()).collect();
+    assert_eq!(names, ["Alpha", "Bravo", "Charlie"]);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn paging_is_stable_when_timestamps_tie(pool: PgPool) {
+    let repo = PartyRepositoryImpl::new();
+    let clock = FixedClock::new(chrono::Utc::now());
+
+    // One transaction, one timestamp: created_at alone cannot order these
+    let mut tx = pool.begin().await.unwrap();
+    let mut expected = Vec::new();
+    for i in 0..7 {
+        let party = Party::new(
+            PartyType::Company,
+            DisplayName::new(format!("Tied {i}")).unwrap(),
+            &clock,
+        );
+        repo.create(&mut *tx, &party).await.unwrap();
+        expected.push(party.id());
+    }
+    tx.commit().await.unwrap();
+
+    let mut paged = Vec::new();
+    for page in 1..=4 {
+        let ListResult { items, .. } = repo
+            .find_paginated(&pool, Pagination::new(page, 2), &PartyFilter::default())
+            .await
+            .unwrap();
+        paged.extend(items.iter().map(|p| p.id()));
+    }
+
+    // No skips or duplicates across pages, in id DESC order
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(paged, expected);
 }
 
 #[tokio::test]
-async fn pagination_page_size() {
+async fn find_by_tin_returns_earliest_match() {
     let pool = get_test_pool().await;
     let repo = PartyRepositoryImpl::new();
 
-    let (items, _) = repo.find_paginated(&pool, 1, 5).await.unwrap();
+    let tin = Tin::new(uuid::Uuid::now_v7().simple().to_string()).unwrap();
+    let with_tin = |base: Party| {
+        Party::from_storage(
+            base.id(),
+            PartyType::Company,
+            base.display_name().clone(),
+            None,
+            Some(tin.clone()),
+            None,
+            true,
+            base.created_at(),
+            base.updated_at(),
+        )
+    };
+    let first = with_tin(fake_party());
+    let second = with_tin(fake_party());
+    repo.create(&pool, &second).await.unwrap();
+    repo.create(&pool, &first).await.unwrap();
+
+    let found = repo.find_by_tin(&pool, &tin).await.unwrap().unwrap();
+    let missing = Tin::new(uuid::Uuid::now_v7().simple().to_string()).unwrap();
+
+    assert_eq!(found.id(), first.id());
+    assert!(repo.find_by_tin(&pool, &missing).await.unwrap().is_none());
+}
 
-    assert!(items.len() <= 5);
+#[tokio::test]
+async fn bulk_update_skips_deleted_parties() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    let kept = seed_one(&pool, &repo).await;
+    let deleted = seed_one(&pool, &repo).await;
+    repo.delete(&pool, deleted.id()).await.unwrap();
+
+    let changes = PartyChanges {
+        party_type: Some(PartyType::Person),
+        ..Default::default()
+    };
+    let affected = repo
+        .bulk_update(
+            &pool,
+            &[kept.id(), deleted.id()],
+            &changes,
+            kept.created_at(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(affected, 1);
+    let found = repo
+        .find_by_id(&pool, kept.id(), false)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(found.party_type(), PartyType::Person);
+    assert!(found.is_active());
 }
 
 // ============================================================================
@@ -153,14 +439,364 @@ async fn update_nonexistent_succeeds_silently() {
     assert!(result.is_ok());
 }
 
+// Isolated database: the corrupt row must not leak into other tests' lists
+#[sqlx::test(migrations = "../../migrations")]
+async fn reading_inverted_timestamps_is_an_internal_error(pool: PgPool) {
+    let repo = PartyRepositoryImpl::new();
+
+    let party = seed_one(&pool, &repo).await;
+    sqlx::query("UPDATE party SET updated_at = created_at - INTERVAL '1 day' WHERE id = $1")
+        .bind(party.id())
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let result = repo.find_by_id(&pool, party.id(), false).await;
+
+    assert!(matches!(result, Err(AppError::Internal(_))));
+}
+
 #[tokio::test]
 async fn pagination_empty_result() {
     let pool = get_test_pool().await;
     let repo = PartyRepositoryImpl::new();
 
     // Request page far beyond data
-    let (items, meta) = repo.find_paginated(&pool, 9999, 10).await.unwrap();
+    let ListResult {
+        items,
+        pagination: meta,
+        ..
+    } = repo
+        .find_paginated(&pool, Pagination::new(9999, 10), &PartyFilter::default())
+        .await
+        .unwrap();
 
     assert!(items.is_empty());
     assert_eq!(meta.page, 9999);
+    assert_pagination_consistent(&meta);
+}
+
+#[tokio::test]
+async fn pagination_huge_page_returns_empty() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    // Offset far beyond any table size must not overflow or error
+    let ListResult {
+        items,
+        pagination: meta,
+        ..
+    } = repo
+        .find_paginated(
+            &pool,
+            Pagination::new(u32::MAX, 100),
+            &PartyFilter::default(),
+        )
+        .await
+        .unwrap();
+
+    assert!(items.is_empty());
+    assert_eq!(meta.page, u32::MAX);
+    assert!(!meta.has_next);
+    assert_pagination_consistent(&meta);
+}
+
+#[tokio::test]
+async fn failed_transaction_rolls_back_earlier_writes() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    let mut tx = pool.begin().await.unwrap();
+    let first = fake_party();
+    let second = fake_party();
+    repo.create(&mut *tx, &first).await.unwrap();
+    repo.create(&mut *tx, &second).await.unwrap();
+
+    // Inserting the same ID again violates the primary key
+    let duplicate = repo.create(&mut *tx, &second).await;
+    assert!(matches!(
+        duplicate,
+        Err(AppError::Domain(DomainError::DuplicateEntity(_)))
+    ));
+    tx.rollback().await.unwrap();
+
+    for party in [&first, &second] {
+        let found = repo.find_by_id(&pool, party.id(), true).await.unwrap();
+        assert!(found.is_none());
+    }
+}
+
+// ============================================================================
+// Filter Tests
+// ============================================================================
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn filter_by_is_active(pool: PgPool) {
+    let repo = PartyRepositoryImpl::new();
+
+    let active = seed_n(&pool, &repo, 3).await;
+    let mut inactive = seed_one(&pool, &repo).await;
+    inactive.deactivate(&SystemClock);
+    repo.update(&pool, &inactive).await.unwrap();
+
+    let only_active = PartyFilter {
+        is_active: Some(true),
+        ..Default::default()
+    };
+    let ListResult {
+        items,
+        pagination: meta,
+        ..
+    } = repo
+        .find_paginated(&pool, Pagination::new(1, 100), &only_active)
+        .await
+        .unwrap();
+    assert_eq!(meta.total, 3);
+    let mut ids: Vec<_> = items.iter().map(|p| p.id()).collect();
+    let mut expected: Vec<_> = active.iter().map(|p| p.id()).collect();
+    ids.sort();
+    expected.sort();
+    assert_eq!(ids, expected);
+
+    let only_inactive = PartyFilter {
+        is_active: Some(false),
+        ..Default::default()
+    };
+    let ListResult {
+        items,
+        pagination: meta,
+        ..
+    } = repo
+        .find_paginated(&pool, Pagination::new(1, 100), &only_inactive)
+        .await
+        .unwrap();
+    assert_eq!(meta.total, 1);
+    assert_eq!(items[0].id(), inactive.id());
+
+    let ListResult {
+        pagination: all, ..
+    } = repo
+        .find_paginated(&pool, Pagination::new(1, 1), &PartyFilter::default())
+        .await
+        .unwrap();
+    assert_eq!(all.total, 4);
+}
+
+// ============================================================================
+// Search Tests
+// ============================================================================
+
+fn search(term: &str) -> PartyFilter {
+    PartyFilter {
+        search: Some(term.to_string()),
+        ..Default::default()
+    }
+}
+
+async fn search_ids(
+    pool: &sqlx::PgPool,
+    repo: &PartyRepositoryImpl,
+    term: &str,
+) -> Vec<uuid::Uuid> {
+    let ListResult { items, .. } = repo
+        .find_paginated(pool, Pagination::new(1, 100), &search(term))
+        .await
+        .unwrap();
+    items.iter().map(|p| p.id()).collect()
+}
+
+#[tokio::test]
+async fn search_matches_whole_word_in_name() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    let word = format!("w{}", uuid::Uuid::now_v7().simple());
+    let party = party(&format!("Northwind {word} Traders"));
+    repo.create(&pool, &party).await.unwrap();
+
+    assert_eq!(search_ids(&pool, &repo, &word).await, [party.id()]);
+}
+
+#[tokio::test]
+async fn search_matches_tin() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    let tin = format!("t{}", uuid::Uuid::now_v7().simple());
+    let base = fake_party();
+    let party = Party::from_storage(
+        base.id(),
+        PartyType::Company,
+        base.display_name().clone(),
+        None,
+        Some(Tin::new(&tin).unwrap()),
+        None,
+        true,
+        base.created_at(),
+        base.updated_at(),
+    );
+    repo.create(&pool, &party).await.unwrap();
+
+    assert_eq!(search_ids(&pool, &repo, &tin).await, [party.id()]);
+    // Partial TIN: no FTS token matches, only ILIKE does
+    assert_eq!(
+        search_ids(&pool, &repo, &tin[tin.len() - 12..]).await,
+        [party.id()]
+    );
+}
+
+#[tokio::test]
+async fn search_matches_words_in_any_order() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    let word = format!("o{}", uuid::Uuid::now_v7().simple());
+    let party = party(&format!("Northwind {word} Traders"));
+    repo.create(&pool, &party).await.unwrap();
+
+    // Not a substring of the name, so only the full-text predicate can match
+    assert_eq!(
+        search_ids(&pool, &repo, &format!("Traders {word}")).await,
+        [party.id()]
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn search_predicate_is_served_by_indexes(pool: PgPool) {
+    let mut tx = pool.begin().await.unwrap();
+    sqlx::query("SET LOCAL enable_seqscan = off")
+        .execute(&mut *tx)
+        .await
+        .unwrap();
+
+    // Same shape as the default-mode search predicate in the repository
+    let plan: Vec<String> = sqlx::query_scalar(
+        "EXPLAIN SELECT id FROM party \
+         WHERE search_vector @@ plainto_tsquery('simple', 'acme') \
+            OR display_name ILIKE '%acme%' \
+            OR legal_name ILIKE '%acme%' \
+            OR tin ILIKE '%acme%'",
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .unwrap();
+    let plan = plan.join("\n");
+
+    assert!(!plan.contains("Seq Scan"), "{plan}");
+    for index in [
+        "idx_party_search_vector",
+        "idx_party_display_name_trgm",
+        "idx_party_legal_name_trgm",
+        "idx_party_tin_trgm",
+    ] {
+        assert!(plan.contains(index), "{index} missing from:\n{plan}");
+    }
+}
+
+#[tokio::test]
+async fn search_falls_back_to_substring_match() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    let word = format!("s{}", uuid::Uuid::now_v7().simple());
+    let party = party(&format!("Contoso{word}"));
+    repo.create(&pool, &party).await.unwrap();
+
+    // Partial word: no FTS token matches, only ILIKE does
+    assert_eq!(search_ids(&pool, &repo, &word[..20]).await, [party.id()]);
+}
+
+#[tokio::test]
+async fn search_ranks_best_match_first() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    let word = format!("r{}", uuid::Uuid::now_v7().simple());
+    // Older, so the default newest-first order would list it last
+    let base = party(&format!("{word} Trading"));
+    let best = Party::from_storage(
+        base.id(),
+        PartyType::Company,
+        base.display_name().clone(),
+        Some(LegalName::new(format!("{word} {word} Ltd.")).unwrap()),
+        None,
+        None,
+        true,
+        base.created_at(),
+        base.updated_at(),
+    );
+    repo.create(&pool, &best).await.unwrap();
+    let weaker = party(&format!("{word} Imports"));
+    repo.create(&pool, &weaker).await.unwrap();
+
+    assert_eq!(
+        search_ids(&pool, &repo, &word).await,
+        [best.id(), weaker.id()]
+    );
+
+    // An explicit sort replaces relevance
+    let by_name = PartyFilter {
+        sort: Some(Sort::asc("display_name")),
+        ..search(&word)
+    };
+    let ListResult { items, .. } = repo
+        .find_paginated(&pool, Pagination::new(1, 10), &by_name)
+        .await
+        .unwrap();
+    let ids: Vec<_> = items.iter().map(|p| p.id()).collect();
+    assert_eq!(ids, [weaker.id(), best.id()]);
+}
+
+#[tokio::test]
+async fn search_ignores_case_by_default() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    let word = format!("Mixed{}", uuid::Uuid::now_v7().simple()).to_uppercase();
+    let party = party(&format!("Acme{word}"));
+    repo.create(&pool, &party).await.unwrap();
+
+    assert_eq!(
+        search_ids(&pool, &repo, &word.to_lowercase()).await,
+        [party.id()]
+    );
+    assert_eq!(search_ids(&pool, &repo, &word).await, [party.id()]);
+}
+
+#[tokio::test]
+async fn case_sensitive_search_requires_exact_case() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new().with_case_sensitive_search(true);
+
+    let word = format!("Mixed{}", uuid::Uuid::now_v7().simple()).to_uppercase();
+    let party = party(&format!("Acme {word}"));
+    repo.create(&pool, &party).await.unwrap();
+
+    // Whole-word lowercase would match through full-text search if it were used
+    assert!(
+        search_ids(&pool, &repo, &word.to_lowercase())
+            .await
+            .is_empty()
+    );
+    assert_eq!(search_ids(&pool, &repo, &word).await, [party.id()]);
+    assert_eq!(
+        search_ids(&pool, &repo, &word[word.len() - 12..]).await,
+        [party.id()]
+    );
+}
+
+#[tokio::test]
+async fn search_treats_wildcards_literally() {
+    let pool = get_test_pool().await;
+    let repo = PartyRepositoryImpl::new();
+
+    let unique = uuid::Uuid::now_v7().simple().to_string();
+    let literal = party(&format!("Promo{unique}50%_off"));
+    let lookalike = party(&format!("Promo{unique}50ab_off"));
+    repo.create(&pool, &literal).await.unwrap();
+    repo.create(&pool, &lookalike).await.unwrap();
+
+    let ids = search_ids(&pool, &repo, &format!("{unique}50%_off")).await;
+
+    assert_eq!(ids, [literal.id()]);
 }
@@ -0,0 +1,97 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::error::{AppError, ValidationError};
+
+/// Position of a keyset-paginated list, exposed to clients only as an opaque token
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cursor {
+    pub last_id: Uuid,
+    pub sort_field: String,
+}
+
+/// Encode a cursor as an opaque base64url token
+pub fn encode_cursor(cursor: &Cursor) -> String {
+    let json = serde_json::to_vec(cursor).expect("cursor serialization is infallible");
+    URL_SAFE_NO_PAD.encode(json)
+}
+
+/// Decode a client-supplied token, rejecting tampered or malformed input with a 400
+pub fn decode_cursor(token: &str) -> Result<Cursor, AppError> {
+    URL_SAFE_NO_PAD
+        .decode(token)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .ok_or_else(|| {
+            AppError::Validation(
+                ValidationError::new("Invalid cursor").with_field("cursor", "Malformed cursor"),
+            )
+        })
+}
+
+// =============================================================================
+// Unit Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor() -> Cursor {
+        Cursor {
+            last_id: Uuid::now_v7(),
+            sort_field: "created_at".to_string(),
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        let cursor = cursor();
+
+        let decoded = decode_cursor(&encode_cursor(&cursor)).unwrap();
+
+        assert_eq!(decoded, cursor);
+    }
+
+    #[test]
+    fn token_is_url_safe() {
+        let token = encode_cursor(&cursor());
+
+        assert!(
+            token
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_token() {
+        for token in ["not a cursor!", "", "e30"] {
+            let result = decode_cursor(token);
+            assert!(
+                matches!(&result, Err(AppError::Validation(err)) if err.fields[0].field == "cursor"),
+                "token {token:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_token_maps_to_bad_request() {
+        use axum::http::StatusCode;
+        use axum::response::IntoResponse;
+
+        let response = decode_cursor("%%%").unwrap_err().into_response();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn rejects_tampered_token() {
+        let mut token = encode_cursor(&cursor());
+        token.truncate(token.len() / 2);
+
+        assert!(decode_cursor(&token).is_err());
+    }
+}
@@ -0,0 +1,234 @@
+use crate::error::{AppError, DomainError, ValidationError};
+
+/// PostgreSQL SQLSTATE codes mapped to client-facing errors
+pub mod sqlstate {
+    pub const UNIQUE_VIOLATION: &str = "23505";
+    pub const FOREIGN_KEY_VIOLATION: &str = "23503";
+    pub const NOT_NULL_VIOLATION: &str = "23502";
+    pub const QUERY_CANCELED: &str = "57014";
+}
+
+/// Translate a sqlx error into the `AppError` clients should see
+///
+/// Constraint violations become 4xx errors, statement timeouts a 504 and
+/// connection failures a 503. Anything unrecognised stays a 500
+/// `AppError::Database`. Every `?` on a sqlx result goes through here via
+/// `From<sqlx::Error> for AppError`.
+pub fn classify(err: sqlx::Error) -> AppError {
+    match &err {
+        sqlx::Error::Database(db_err) => {
+            let code = db_err.code();
+            match code.as_deref() {
+                Some(sqlstate::UNIQUE_VIOLATION) => {
+                    // Constraint names describe the schema, so they are logged, not returned
+                    tracing::warn!(
+                        constraint = db_err.constraint(),
+                        "Unique violation: {}",
+                        db_err.message()
+                    );
+                    AppError::Domain(DomainError::DuplicateEntity(
+                        "A record with the same unique value already exists".to_string(),
+                    ))
+                }
+                Some(sqlstate::FOREIGN_KEY_VIOLATION) => {
+                    AppError::Domain(DomainError::BusinessRuleViolation(
+                        "The record references, or is referenced by, another record".to_string(),
+                    ))
+                }
+                Some(sqlstate::NOT_NULL_VIOLATION) => not_null_violation(db_err.message()),
+                Some(sqlstate::QUERY_CANCELED) => {
+                    AppError::Timeout("The database query took too long".to_string())
+                }
+                _ => AppError::Database(err),
+            }
+        }
+        sqlx::Error::PoolTimedOut
+        | sqlx::Error::PoolClosed
+        | sqlx::Error::Io(_)
+        | sqlx::Error::Tls(_) => {
+            tracing::error!("Database connection error: {:?}", err);
+            AppError::ServiceUnavailable("The database is unavailable".to_string())
+        }
+        _ => AppError::Database(err),
+    }
+}
+
+/// Postgres reports `null value in column "legal_name" of relation ...`
+fn not_null_violation(message: &str) -> AppError {
+    let Some(column) = message.split('"').nth(1) else {
+        return AppError::Validation(ValidationError::new("A required value is missing"));
+    };
+
+    let field = to_camel_case(column);
+    AppError::Validation(
+        ValidationError::new(format!("`{field}` is required"))
+            .with_field(field, "Value is required"),
+    )
+}
+
+/// Column names are snake_case; request fields are camelCase
+fn to_camel_case(column: &str) -> String {
+    let mut parts = column.split('_');
+    let mut field = parts.next().unwrap_or_default().to_string();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            field.extend(first.to_uppercase());
+            field.push_str(chars.as_str());
+        }
+    }
+    field
+}
+
+// =============================================================================
+// Unit Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::StatusCode;
+    use axum::response::IntoResponse;
+    use sqlx::error::{DatabaseError, ErrorKind};
+    use std::borrow::Cow;
+
+    /// Stand-in for a Postgres error carrying a given SQLSTATE
+    #[derive(Debug)]
+    struct FakeDbError {
+        code: &'static str,
+        message: &'static str,
+        constraint: Option<&'static str>,
+    }
+
+    impl std::fmt::Display for FakeDbError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.message)
+        }
+    }
+
+    impl std::error::Error for FakeDbError {}
+
+    impl DatabaseError for FakeDbError {
+        fn message(&self) -> &str {
+            self.message
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed(self.code))
+        }
+
+        fn constraint(&self) -> Option<&str> {
+            self.constraint
+        }
+
+        fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    fn db_error(code: &'static str, message: &'static str) -> sqlx::Error {
+        sqlx::Error::Database(Box::new(FakeDbError {
+            code,
+            message,
+            constraint: None,
+        }))
+    }
+
+    fn status_of(err: AppError) -> StatusCode {
+        err.into_response().status()
+    }
+
+    #[test]
+    fn unique_violation_is_conflict_without_constraint_name() {
+        let err = sqlx::Error::Database(Box::new(FakeDbError {
+            code: sqlstate::UNIQUE_VIOLATION,
+            message: "duplicate key value violates unique constraint",
+            constraint: Some("party_tin_key"),
+        }));
+
+        let app_err = classify(err);
+
+        assert!(
+            matches!(&app_err, AppError::Domain(DomainError::DuplicateEntity(msg)) if !msg.contains("party_tin_key"))
+        );
+        assert_eq!(status_of(app_err), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn foreign_key_violation_is_business_rule() {
+        let app_err = classify(db_error(sqlstate::FOREIGN_KEY_VIOLATION, "fk"));
+
+        assert!(matches!(
+            app_err,
+            AppError::Domain(DomainError::BusinessRuleViolation(_))
+        ));
+        assert_eq!(status_of(app_err), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn not_null_violation_is_validation_on_column() {
+        let app_err = classify(db_error(
+            sqlstate::NOT_NULL_VIOLATION,
+            r#"null value in column "display_name" of relation "party" violates not-null constraint"#,
+        ));
+
+        let AppError::Validation(validation) = &app_err else {
+            panic!("expected validation error, got {app_err:?}");
+        };
+        assert_eq!(validation.fields[0].field, "displayName");
+        assert_eq!(status_of(app_err), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn query_canceled_is_timeout() {
+        let app_err = classify(db_error(
+            sqlstate::QUERY_CANCELED,
+            "canceling statement due to statement timeout",
+        ));
+
+        assert!(matches!(app_err, AppError::Timeout(_)));
+        assert_eq!(status_of(app_err), StatusCode::GATEWAY_TIMEOUT);
+    }
+
+    #[test]
+    fn connection_errors_are_service_unavailable() {
+        for err in [sqlx::Error::PoolTimedOut, sqlx::Error::PoolClosed] {
+            let app_err = classify(err);
+
+            assert!(matches!(app_err, AppError::ServiceUnavailable(_)));
+            assert_eq!(status_of(app_err), StatusCode::SERVICE_UNAVAILABLE);
+        }
+    }
+
+    #[test]
+    fn unrecognised_errors_stay_internal() {
+        let app_err = classify(db_error("XX000", "internal"));
+
+        assert!(matches!(app_err, AppError::Database(_)));
+        assert_eq!(status_of(app_err), StatusCode::INTERNAL_SERVER_ERROR);
+
+        assert!(matches!(
+            classify(sqlx::Error::RowNotFound),
+            AppError::Database(_)
+        ));
+    }
+
+    #[test]
+    fn from_sqlx_error_classifies() {
+        let app_err: AppError = sqlx::Error::PoolTimedOut.into();
+
+        assert!(matches!(app_err, AppError::ServiceUnavailable(_)));
+    }
+}
@@ -1,7 +1,10 @@
 use axum::http::StatusCode;
 use axum::response::{IntoResponse, Response};
+use serde::Serialize;
 use thiserror::Error;
+use utoipa::ToSchema;
 
+use crate::locale::Locale;
 use crate::response::{ErrorResponse, FieldError};
 
 /// Error codes for programmatic error handling by clients
@@ -16,22 +19,158 @@ pub mod error_codes {
     pub const VALIDATION_ERROR: &str = "validation_error";
     pub const UNAUTHORIZED: &str = "unauthorized";
     pub const FORBIDDEN: &str = "forbidden";
+    pub const SERVICE_UNAVAILABLE: &str = "service_unavailable";
+    pub const TIMEOUT: &str = "timeout";
     pub const INTERNAL_ERROR: &str = "internal_error";
 }
 
+/// A documented error code with the status and title responses carry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorCodeInfo {
+    #[schema(example = "not_found")]
+    pub code: &'static str,
+    #[schema(example = 404)]
+    pub status: u16,
+    #[schema(example = "Not Found")]
+    pub title: &'static str,
+    /// Vietnamese `title`, sent to clients preferring `vi`
+    #[serde(skip)]
+    pub title_vi: &'static str,
+    /// When clients can expect this code
+    pub description: &'static str,
+}
+
+impl ErrorCodeInfo {
+    const fn new(
+        code: &'static str,
+        status: StatusCode,
+        (title, title_vi): (&'static str, &'static str),
+        description: &'static str,
+    ) -> Self {
+        Self {
+            code,
+            status: status.as_u16(),
+            title,
+            title_vi,
+            description,
+        }
+    }
+
+    pub fn localized_title(&self, locale: Locale) -> &'static str {
+        locale.select(self.title, self.title_vi)
+    }
+}
+
+/// Every error code the API can return; [`AppError::to_error_response`]
+/// takes status and title from here, so the published catalog cannot drift
+pub mod error_catalog {
+    use super::{ErrorCodeInfo, StatusCode, error_codes};
+
+    pub const INVALID_VALUE: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::INVALID_VALUE,
+        StatusCode::BAD_REQUEST,
+        ("Invalid Value", "Giá trị không hợp lệ"),
+        "A value broke a domain rule, such as a name that is too long",
+    );
+    pub const BUSINESS_RULE_VIOLATION: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::BUSINESS_RULE_VIOLATION,
+        StatusCode::UNPROCESSABLE_ENTITY,
+        ("Business Rule Violation", "Vi phạm quy tắc nghiệp vụ"),
+        "The request is well-formed but not allowed in the current state",
+    );
+    pub const ENTITY_NOT_FOUND: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::ENTITY_NOT_FOUND,
+        StatusCode::NOT_FOUND,
+        ("Entity Not Found", "Không tìm thấy thực thể"),
+        "A referenced entity does not exist",
+    );
+    pub const DUPLICATE_ENTITY: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::DUPLICATE_ENTITY,
+        StatusCode::CONFLICT,
+        ("Duplicate Entity", "Thực thể bị trùng lặp"),
+        "The entity would violate a uniqueness constraint",
+    );
+    pub const DATABASE_ERROR: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::DATABASE_ERROR,
+        StatusCode::INTERNAL_SERVER_ERROR,
+        ("Database Error", "Lỗi cơ sở dữ liệu"),
+        "An unexpected database failure; safe to retry later",
+    );
+    pub const NOT_FOUND: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::NOT_FOUND,
+        StatusCode::NOT_FOUND,
+        ("Not Found", "Không tìm thấy"),
+        "The requested resource does not exist",
+    );
+    pub const VALIDATION_ERROR: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::VALIDATION_ERROR,
+        StatusCode::BAD_REQUEST,
+        ("Validation Error", "Dữ liệu không hợp lệ"),
+        "The request failed validation; `errors` lists the offending fields",
+    );
+    pub const UNAUTHORIZED: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::UNAUTHORIZED,
+        StatusCode::UNAUTHORIZED,
+        ("Unauthorized", "Chưa xác thực"),
+        "Authentication is missing or invalid",
+    );
+    pub const FORBIDDEN: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::FORBIDDEN,
+        StatusCode::FORBIDDEN,
+        ("Forbidden", "Không có quyền truy cập"),
+        "The caller may not perform this action",
+    );
+    pub const SERVICE_UNAVAILABLE: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::SERVICE_UNAVAILABLE,
+        StatusCode::SERVICE_UNAVAILABLE,
+        ("Service Unavailable", "Dịch vụ không khả dụng"),
+        "A dependency such as the database is unreachable; retry later",
+    );
+    pub const TIMEOUT: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::TIMEOUT,
+        StatusCode::GATEWAY_TIMEOUT,
+        ("Gateway Timeout", "Hết thời gian chờ"),
+        "The operation took too long and was cancelled",
+    );
+    pub const INTERNAL_ERROR: ErrorCodeInfo = ErrorCodeInfo::new(
+        error_codes::INTERNAL_ERROR,
+        StatusCode::INTERNAL_SERVER_ERROR,
+        ("Internal Server Error", "Lỗi máy chủ nội bộ"),
+        "An unexpected server failure",
+    );
+
+    pub const ALL: &[ErrorCodeInfo] = &[
+        INVALID_VALUE,
+        BUSINESS_RULE_VIOLATION,
+        ENTITY_NOT_FOUND,
+        DUPLICATE_ENTITY,
+        DATABASE_ERROR,
+        NOT_FOUND,
+        VALIDATION_ERROR,
+        UNAUTHORIZED,
+        FORBIDDEN,
+        SERVICE_UNAVAILABLE,
+        TIMEOUT,
+        INTERNAL_ERROR,
+    ];
+}
+
 #[derive(Debug, Error)]
 pub enum AppError {
     #[error("Domain error: {0}")]
     Domain(#[from] DomainError),
 
+    /// Unclassified database failure; build via `From`, which routes
+    /// through [`crate::db_error::classify`]
     #[error("Database error: {0}")]
-    Database(#[from] sqlx::Error),
+    Database(sqlx::Error),
 
     #[error("Not found: {0}")]
     NotFound(String),
 
     #[error("Validation error")]
-    Validation(ValidationError),
+    Validation(#[from] ValidationError),
 
     #[error("Unauthorized")]
     Unauthorized,
@@ -39,10 +178,22 @@ pub enum AppError {
     #[error("Forbidden: {0}")]
     Forbidden(String),
 
+    #[error("Service unavailable: {0}")]
+    ServiceUnavailable(String),
+
+    #[error("Timeout: {0}")]
+    Timeout(String),
+
     #[error("Internal server error: {0}")]
     Internal(String),
 }
 
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        crate::db_error::classify(err)
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum DomainError {
     #[error("Invalid value: {0}")]
@@ -58,6 +209,18 @@ pub enum DomainError {
     DuplicateEntity(String),
 }
 
+impl DomainError {
+    /// The underlying message, without the variant prefix
+    pub fn message(&self) -> &str {
+        match self {
+            DomainError::InvalidValue(msg)
+            | DomainError::BusinessRuleViolation(msg)
+            | DomainError::EntityNotFound(msg)
+            | DomainError::DuplicateEntity(msg) => msg,
+        }
+    }
+}
+
 /// Structured validation error with field-level details
 #[derive(Debug, Clone)]
 pub struct ValidationError {
@@ -73,6 +236,12 @@ impl ValidationError {
         }
     }
 
+    /// Wrap a value-object failure as an error on a single request field
+    pub fn for_field(field: impl Into<String>, err: DomainError) -> Self {
+        let message = err.message().to_string();
+        Self::new(message.clone()).with_field(field, message)
+    }
+
     pub fn with_field(mut self, field: impl Into<String>, message: impl Into<String>) -> Self {
         self.fields.push(FieldError {
             field: field.into(),
@@ -99,16 +268,11 @@ impl std::error::Error for ValidationError {}
 
 impl AppError {
     /// Helper to create ErrorResponse with common fields
-    fn create_error_response(
-        error_code: &str,
-        title: impl Into<String>,
-        status: StatusCode,
-        detail: impl Into<String>,
-    ) -> ErrorResponse {
+    fn create_error_response(info: &ErrorCodeInfo, detail: impl Into<String>) -> ErrorResponse {
         ErrorResponse {
-            error_type: format!("urn:error:{}", error_code),
-            title: title.into(),
-            status: status.as_u16(),
+            error_type: format!("urn:error:{}", info.code),
+            title: info.localized_title(Locale::current()).to_string(),
+            status: info.status,
             detail: detail.into(),
             instance: None,
             errors: None,
@@ -116,54 +280,36 @@ impl AppError {
     }
 
     /// Convert AppError to ErrorResponse with proper RFC 7807 structure
-    fn to_error_response(&self) -> ErrorResponse {
+    pub fn to_error_response(&self) -> ErrorResponse {
         match self {
             AppError::Domain(domain_err) => match domain_err {
-                DomainError::InvalidValue(msg) => Self::create_error_response(
-                    error_codes::INVALID_VALUE,
-                    "Invalid Value",
-                    StatusCode::BAD_REQUEST,
-                    msg,
-                ),
-                DomainError::BusinessRuleViolation(msg) => Self::create_error_response(
-                    error_codes::BUSINESS_RULE_VIOLATION,
-                    "Business Rule Violation",
-                    StatusCode::UNPROCESSABLE_ENTITY,
-                    msg,
-                ),
-                DomainError::EntityNotFound(msg) => Self::create_error_response(
-                    error_codes::ENTITY_NOT_FOUND,
-                    "Entity Not Found",
-                    StatusCode::NOT_FOUND,
-                    msg,
-                ),
-                DomainError::DuplicateEntity(msg) => Self::create_error_response(
-                    error_codes::DUPLICATE_ENTITY,
-                    "Duplicate Entity",
-                    StatusCode::CONFLICT,
-                    msg,
-                ),
+                DomainError::InvalidValue(msg) => {
+                    Self::create_error_response(&error_catalog::INVALID_VALUE, msg)
+                }
+                DomainError::BusinessRuleViolation(msg) => {
+                    Self::create_error_response(&error_catalog::BUSINESS_RULE_VIOLATION, msg)
+                }
+                DomainError::EntityNotFound(msg) => {
+                    Self::create_error_response(&error_catalog::ENTITY_NOT_FOUND, msg)
+                }
+                DomainError::DuplicateEntity(msg) => {
+                    Self::create_error_response(&error_catalog::DUPLICATE_ENTITY, msg)
+                }
             },
             AppError::Database(err) => {
                 tracing::error!("Database error: {:?}", err);
                 Self::create_error_response(
-                    error_codes::DATABASE_ERROR,
-                    "Database Error",
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "A database error occurred. Please try again later.",
+                    &error_catalog::DATABASE_ERROR,
+                    Locale::current().select(
+                        "A database error occurred. Please try again later.",
+                        "Đã xảy ra lỗi cơ sở dữ liệu. Vui lòng thử lại sau.",
+                    ),
                 )
             }
-            AppError::NotFound(msg) => Self::create_error_response(
-                error_codes::NOT_FOUND,
-                "Not Found",
-                StatusCode::NOT_FOUND,
-                msg,
-            ),
+            AppError::NotFound(msg) => Self::create_error_response(&error_catalog::NOT_FOUND, msg),
             AppError::Validation(validation_err) => {
                 let mut response = Self::create_error_response(
-                    error_codes::VALIDATION_ERROR,
-                    "Validation Error",
-                    StatusCode::BAD_REQUEST,
+                    &error_catalog::VALIDATION_ERROR,
                     &validation_err.message,
                 );
                 if !validation_err.fields.is_empty() {
@@ -172,24 +318,25 @@ impl AppError {
                 response
             }
             AppError::Unauthorized => Self::create_error_response(
-                error_codes::UNAUTHORIZED,
-                "Unauthorized",
-                StatusCode::UNAUTHORIZED,
-                "Authentication is required to access this resource.",
-            ),
-            AppError::Forbidden(msg) => Self::create_error_response(
-                error_codes::FORBIDDEN,
-                "Forbidden",
-                StatusCode::FORBIDDEN,
-                msg,
+                &error_catalog::UNAUTHORIZED,
+                Locale::current().select(
+                    "Authentication is required to access this resource.",
+                    "Cần xác thực để truy cập tài nguyên này.",
+                ),
             ),
+            AppError::Forbidden(msg) => Self::create_error_response(&error_catalog::FORBIDDEN, msg),
+            AppError::ServiceUnavailable(msg) => {
+                Self::create_error_response(&error_catalog::SERVICE_UNAVAILABLE, msg)
+            }
+            AppError::Timeout(msg) => Self::create_error_response(&error_catalog::TIMEOUT, msg),
             AppError::Internal(msg) => {
                 tracing::error!("Internal error: {}", msg);
                 Self::create_error_response(
-                    error_codes::INTERNAL_ERROR,
-                    "Internal Server Error",
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "An unexpected error occurred. Please try again later.",
+                    &error_catalog::INTERNAL_ERROR,
+                    Locale::current().select(
+                        "An unexpected error occurred. Please try again later.",
+                        "Đã xảy ra lỗi không mong muốn. Vui lòng thử lại sau.",
+                    ),
                 )
             }
         }
@@ -201,3 +348,71 @@ impl IntoResponse for AppError {
         self.to_error_response().into_response()
     }
 }
+
+// =============================================================================
+// Unit Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validate() -> Result<(), ValidationError> {
+        let mut error = ValidationError::new("Invalid request body");
+        error.add_field("displayName", "Display name cannot be empty");
+        error.add_field("tin", "TIN too long (max 50 chars)");
+        Err(error)
+    }
+
+    #[test]
+    fn question_mark_converts_validation_error() {
+        fn run() -> Result<(), AppError> {
+            validate()?;
+            Ok(())
+        }
+
+        let Err(AppError::Validation(error)) = run() else {
+            panic!("expected a validation error");
+        };
+        assert_eq!(error.message, "Invalid request body");
+        let fields: Vec<_> = error.fields.iter().map(|f| f.field.as_str()).collect();
+        assert_eq!(fields, ["displayName", "tin"]);
+        assert_eq!(error.fields[1].message, "TIN too long (max 50 chars)");
+    }
+
+    #[test]
+    fn every_error_response_is_in_the_catalog() {
+        let errors = [
+            AppError::Domain(DomainError::InvalidValue("x".into())),
+            AppError::Domain(DomainError::BusinessRuleViolation("x".into())),
+            AppError::Domain(DomainError::EntityNotFound("x".into())),
+            AppError::Domain(DomainError::DuplicateEntity("x".into())),
+            AppError::Database(sqlx::Error::PoolClosed),
+            AppError::NotFound("x".into()),
+            AppError::Validation(ValidationError::new("x")),
+            AppError::Unauthorized,
+            AppError::Forbidden("x".into()),
+            AppError::ServiceUnavailable("x".into()),
+            AppError::Timeout("x".into()),
+            AppError::Internal("x".into()),
+        ];
+
+        for error in errors {
+            let response = error.to_error_response();
+            let entry = error_catalog::ALL
+                .iter()
+                .find(|info| response.error_type == format!("urn:error:{}", info.code))
+                .unwrap_or_else(|| panic!("{} missing from catalog", response.error_type));
+            assert_eq!(response.status, entry.status);
+            assert_eq!(response.title, entry.title);
+        }
+    }
+
+    #[test]
+    fn catalog_codes_are_unique() {
+        let mut codes: Vec<_> = error_catalog::ALL.iter().map(|info| info.code).collect();
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), error_catalog::ALL.len());
+    }
+}
@@ -0,0 +1,107 @@
+//! Content-derived entity tags and `If-None-Match` matching
+
+use axum::http::{HeaderMap, header};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+
+/// Strong ETag from a SHA-256 of `value`'s canonical JSON
+///
+/// Going through `serde_json::Value` sorts object keys, so equal content
+/// always hashes equally and any field change yields a different tag.
+pub fn content_etag<T: Serialize>(value: &T) -> String {
+    let canonical = serde_json::to_value(value)
+        .and_then(|json| serde_json::to_vec(&json))
+        .unwrap_or_default();
+    let digest = Sha256::digest(&canonical);
+
+    // 128 bits is plenty to tell versions of one resource apart
+    let mut tag = String::with_capacity(34);
+    tag.push('"');
+    for byte in &digest[..16] {
+        let _ = write!(tag, "{byte:02x}");
+    }
+    tag.push('"');
+    tag
+}
+
+/// Whether the request's `If-None-Match` already names `etag`
+///
+/// Uses weak comparison, as RFC 9110 requires for `If-None-Match`.
+pub fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    let strip_weak = |tag: &str| tag.trim().trim_start_matches("W/").to_string();
+    let etag = strip_weak(etag);
+
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|candidate| candidate.trim() == "*" || strip_weak(candidate) == etag)
+}
+
+// =============================================================================
+// Unit Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use serde_json::json;
+
+    #[test]
+    fn identical_content_hashes_equally() {
+        let a = json!({ "id": 1, "name": "Acme" });
+        let b = json!({ "name": "Acme", "id": 1 });
+
+        assert_eq!(content_etag(&a), content_etag(&b));
+    }
+
+    #[test]
+    fn field_change_alters_tag() {
+        let before = json!({ "id": 1, "name": "Acme" });
+        let after = json!({ "id": 1, "name": "Acme Ltd." });
+
+        assert_ne!(content_etag(&before), content_etag(&after));
+    }
+
+    #[test]
+    fn tag_is_quoted_hex() {
+        let tag = content_etag(&json!({ "id": 1 }));
+
+        assert_eq!(tag.len(), 34);
+        assert!(tag.starts_with('"') && tag.ends_with('"'));
+        assert!(tag[1..33].chars().all(|c| c.is_ascii_hexdigit()));
+    }
+
+    fn headers(if_none_match: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::IF_NONE_MATCH,
+            HeaderValue::from_str(if_none_match).unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn if_none_match_finds_tag_in_list() {
+        assert!(if_none_match(&headers(r#""a", "b""#), r#""b""#));
+        assert!(!if_none_match(&headers(r#""a", "b""#), r#""c""#));
+    }
+
+    #[test]
+    fn if_none_match_compares_weakly() {
+        assert!(if_none_match(&headers(r#"W/"a""#), r#""a""#));
+    }
+
+    #[test]
+    fn if_none_match_wildcard_matches_anything() {
+        assert!(if_none_match(&headers("*"), r#""a""#));
+    }
+
+    #[test]
+    fn missing_header_never_matches() {
+        assert!(!if_none_match(&HeaderMap::new(), r#""a""#));
+    }
+}
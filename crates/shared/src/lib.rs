@@ -1,11 +1,24 @@
+pub mod cursor;
+pub mod db_error;
 pub mod error;
+pub mod etag;
+pub mod locale;
 pub mod pagination;
 pub mod response;
+pub mod sort;
+pub mod sql;
+pub mod timestamp;
 
 // Re-export commonly used types
-pub use error::{AppError, DomainError, ValidationError};
-pub use pagination::{PageParams, PaginationMeta};
+pub use cursor::{Cursor, decode_cursor, encode_cursor};
+pub use error::{AppError, DomainError, ErrorCodeInfo, ValidationError};
+pub use etag::{content_etag, if_none_match};
+pub use locale::Locale;
+pub use pagination::{ListResult, PageParams, Pagination, PaginationMeta};
 pub use response::{ErrorResponse, FieldError, Meta, SuccessResponse};
+pub use sort::{Sort, SortDirection};
 
 // Re-export helper functions for convenience
-pub use response::{accepted, created, no_content, success, success_with_pagination};
+pub use response::{
+    accepted, created, created_at_location, no_content, success, success_with_pagination,
+};
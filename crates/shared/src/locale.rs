@@ -0,0 +1,111 @@
+//! Response language, negotiated per request from `Accept-Language`
+
+use std::future::Future;
+
+tokio::task_local! {
+    static CURRENT: Locale;
+}
+
+/// Languages error titles and fixed messages are translated into
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    En,
+    Vi,
+}
+
+impl Locale {
+    /// BCP 47 tag, as sent in `Content-Language`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Vi => "vi",
+        }
+    }
+
+    /// Pick the supported locale the client ranks highest in `Accept-Language`
+    ///
+    /// Region subtags are ignored (`vi-VN` is `vi`); anything unsupported,
+    /// malformed or absent yields English.
+    pub fn from_accept_language(header: &str) -> Self {
+        let mut best: Option<(Self, f32)> = None;
+        for item in header.split(',') {
+            let mut parts = item.split(';');
+            let tag = parts.next().unwrap_or_default().trim();
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok());
+            let (Some(locale), Some(quality)) = (Self::from_tag(tag), quality) else {
+                continue;
+            };
+            if quality > 0.0 && best.is_none_or(|(_, q)| quality > q) {
+                best = Some((locale, quality));
+            }
+        }
+        best.map(|(locale, _)| locale).unwrap_or_default()
+    }
+
+    fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next()?;
+        match primary.to_ascii_lowercase().as_str() {
+            "en" => Some(Locale::En),
+            "vi" => Some(Locale::Vi),
+            _ => None,
+        }
+    }
+
+    /// Locale of the request being handled; English outside [`Locale::scope`]
+    pub fn current() -> Self {
+        CURRENT.try_with(|locale| *locale).unwrap_or_default()
+    }
+
+    /// Run `future` with `self` as the [`Locale::current`] locale
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        CURRENT.scope(self, future).await
+    }
+
+    /// Pick the string for this locale
+    pub fn select(self, en: &'static str, vi: &'static str) -> &'static str {
+        match self {
+            Locale::En => en,
+            Locale::Vi => vi,
+        }
+    }
+}
+
+// =============================================================================
+// Unit Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_to_english() {
+        assert_eq!(Locale::from_accept_language(""), Locale::En);
+        assert_eq!(Locale::from_accept_language("fr-FR, de"), Locale::En);
+    }
+
+    #[test]
+    fn ignores_region_subtag() {
+        assert_eq!(Locale::from_accept_language("vi-VN"), Locale::Vi);
+    }
+
+    #[test]
+    fn honours_quality_values() {
+        assert_eq!(
+            Locale::from_accept_language("en;q=0.5, vi;q=0.9"),
+            Locale::Vi
+        );
+        assert_eq!(Locale::from_accept_language("vi;q=0.4, en"), Locale::En);
+        assert_eq!(Locale::from_accept_language("vi;q=0, fr"), Locale::En);
+    }
+
+    #[tokio::test]
+    async fn current_follows_scope() {
+        assert_eq!(Locale::current(), Locale::En);
+        let inside = Locale::Vi.scope(async { Locale::current() }).await;
+        assert_eq!(inside, Locale::Vi);
+    }
+}
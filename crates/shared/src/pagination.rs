@@ -1,6 +1,13 @@
 use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 
+/// Page size used when neither the client nor the endpoint supplies one
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+
+/// Offset pagination query parameters
+///
+/// `page_size` is left unset by deserialization so each list endpoint can apply
+/// its own default through [`PageParams::with_default`].
 #[derive(Debug, Clone, Deserialize, IntoParams)]
 #[serde(rename_all = "kebab-case")]
 pub struct PageParams {
@@ -8,44 +15,99 @@ pub struct PageParams {
     #[param(example = 1, minimum = 1)]
     pub page: u32,
 
-    #[serde(default = "default_page_size")]
     #[param(example = 20, minimum = 1, maximum = 100)]
-    pub page_size: u32,
+    pub page_size: Option<u32>,
 }
 
 const fn default_page() -> u32 {
     1
 }
 
-const fn default_page_size() -> u32 {
-    20
-}
-
 impl PageParams {
+    /// Effective page size, falling back to [`DEFAULT_PAGE_SIZE`] when unset
+    pub fn page_size(&self) -> u32 {
+        self.page_size.unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+
     pub fn offset(&self) -> u64 {
-        u64::from(self.page.saturating_sub(1)) * u64::from(self.page_size)
+        u64::from(self.page.saturating_sub(1)) * u64::from(self.page_size())
     }
 
     pub fn limit(&self) -> u64 {
-        u64::from(self.page_size)
+        u64::from(self.page_size())
     }
 
-    pub fn validate(mut self, max_page_size: u32) -> Self {
+    /// Normalize with the global default page size
+    pub fn validate(self, max_page_size: u32) -> Self {
+        self.with_default(DEFAULT_PAGE_SIZE, max_page_size)
+    }
+
+    /// Normalize with an endpoint-specific default page size
+    pub fn with_default(mut self, default_page_size: u32, max_page_size: u32) -> Self {
         self.page = self.page.max(1);
-        self.page_size = self.page_size.clamp(1, max_page_size);
+        self.page_size = Some(
+            self.page_size
+                .unwrap_or(default_page_size)
+                .clamp(1, max_page_size),
+        );
         self
     }
 }
 
+impl PageParams {
+    /// Resolved page and page size to hand to use cases and repositories
+    pub fn pagination(&self) -> Pagination {
+        Pagination::new(self.page, self.page_size())
+    }
+}
+
 impl Default for PageParams {
     fn default() -> Self {
         Self {
             page: default_page(),
-            page_size: default_page_size(),
+            page_size: None,
         }
     }
 }
 
+/// A resolved page request, passed unchanged from handler to repository
+///
+/// `page` and `page_size` are always at least 1: [`Pagination::new`] clamps
+/// zeros, so repositories never divide by or `LIMIT` to a zero page size, and
+/// callers skipping [`PageParams::with_default`] get the same behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pagination {
+    page: u32,
+    page_size: u32,
+}
+
+impl Pagination {
+    /// Page request with `page` and `page_size` clamped to at least 1
+    pub fn new(page: u32, page_size: u32) -> Self {
+        Self {
+            page: page.max(1),
+            page_size: page_size.max(1),
+        }
+    }
+
+    pub fn page(&self) -> u32 {
+        self.page
+    }
+
+    pub fn page_size(&self) -> u32 {
+        self.page_size
+    }
+
+    /// Rows to skip; u64 so the product of two u32s never overflows
+    pub fn offset(&self) -> u64 {
+        u64::from(self.page.saturating_sub(1)) * u64::from(self.page_size)
+    }
+
+    pub fn limit(&self) -> u64 {
+        u64::from(self.page_size)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PaginationMeta {
@@ -53,10 +115,11 @@ pub struct PaginationMeta {
     pub page: u32,
     #[schema(example = 20)]
     pub page_size: u32,
+    /// Total matching rows; u64 so very large tables aren't truncated
     #[schema(example = 100)]
-    pub total: u32,
+    pub total: u64,
     #[schema(example = 5)]
-    pub total_pages: u32,
+    pub total_pages: u64,
     #[schema(example = true)]
     pub has_next: bool,
     #[schema(example = false)]
@@ -64,24 +127,172 @@ pub struct PaginationMeta {
 }
 
 impl PaginationMeta {
-    pub fn new(page: u32, page_size: u32, total: u32) -> Self {
-        let total_pages = if total == 0 || page_size == 0 {
-            1
-        } else {
-            (total + page_size - 1) / page_size // Ceiling division
-        };
+    /// Page metadata; `page` and `page_size` are clamped like [`Pagination::new`]
+    pub fn new(page: u32, page_size: u32, total: u64) -> Self {
+        let Pagination { page, page_size } = Pagination::new(page, page_size);
+        // An empty result is still one (empty) page
+        let total_pages = total.div_ceil(u64::from(page_size)).max(1);
 
         Self {
             page,
             page_size,
             total,
             total_pages,
-            has_next: page < total_pages,
+            has_next: u64::from(page) < total_pages,
             has_prev: page > 1,
         }
     }
 
-    pub fn from_params(params: &PageParams, total: u32) -> Self {
-        Self::new(params.page, params.page_size, total)
+    pub fn from_params(params: &PageParams, total: u64) -> Self {
+        Self::for_page(params.pagination(), total)
+    }
+
+    pub fn for_page(pagination: Pagination, total: u64) -> Self {
+        Self::new(pagination.page, pagination.page_size, total)
+    }
+}
+
+/// One page of results returned by repository list queries
+#[derive(Debug, Clone)]
+pub struct ListResult<T> {
+    pub items: Vec<T>,
+    pub pagination: PaginationMeta,
+    /// Opaque token for fetching the next page, when keyset pagination is used
+    pub cursor: Option<String>,
+}
+
+impl<T> ListResult<T> {
+    pub fn new(items: Vec<T>, pagination: PaginationMeta) -> Self {
+        Self {
+            items,
+            pagination,
+            cursor: None,
+        }
+    }
+
+    pub fn with_cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+// =============================================================================
+// Unit Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(query: serde_json::Value) -> PageParams {
+        serde_json::from_value(query).unwrap()
+    }
+
+    #[test]
+    fn omitted_page_size_stays_unset() {
+        let params = parse(serde_json::json!({}));
+
+        assert_eq!(params.page, 1);
+        assert_eq!(params.page_size, None);
+    }
+
+    #[test]
+    fn with_default_applies_endpoint_default() {
+        let small_default = parse(serde_json::json!({})).with_default(20, 100);
+        let large_default = parse(serde_json::json!({})).with_default(50, 100);
+
+        assert_eq!(small_default.page_size(), 20);
+        assert_eq!(large_default.page_size(), 50);
+    }
+
+    #[test]
+    fn with_default_keeps_client_page_size() {
+        let params = parse(serde_json::json!({ "page-size": 5 })).with_default(50, 100);
+
+        assert_eq!(params.page_size(), 5);
+    }
+
+    #[test]
+    fn with_default_clamps_to_bounds() {
+        let too_big = parse(serde_json::json!({ "page-size": 500 })).with_default(20, 100);
+        let zero = parse(serde_json::json!({ "page": 0, "page-size": 0 })).with_default(20, 100);
+
+        assert_eq!(too_big.page_size(), 100);
+        assert_eq!(zero.page_size(), 1);
+        assert_eq!(zero.page, 1);
+    }
+
+    #[test]
+    fn pagination_from_params_matches_params() {
+        let params = parse(serde_json::json!({ "page": 3, "page-size": 25 })).with_default(20, 100);
+
+        let pagination = params.pagination();
+
+        assert_eq!(pagination, Pagination::new(3, 25));
+        assert_eq!(pagination.offset(), params.offset());
+        assert_eq!(pagination.limit(), params.limit());
+    }
+
+    #[test]
+    fn pagination_offset_does_not_overflow() {
+        let pagination = Pagination::new(u32::MAX, u32::MAX);
+
+        assert_eq!(
+            pagination.offset(),
+            u64::from(u32::MAX - 1) * u64::from(u32::MAX)
+        );
+    }
+
+    #[test]
+    fn pagination_meta_keeps_totals_beyond_u32() {
+        let total = u64::from(u32::MAX) * 3;
+
+        let meta = PaginationMeta::new(1, 100, total);
+
+        assert_eq!(meta.total, total);
+        assert_eq!(meta.total_pages, total.div_ceil(100));
+        assert!(meta.has_next);
+    }
+
+    #[test]
+    fn pagination_meta_last_page_has_no_next() {
+        let meta = PaginationMeta::new(5, 20, 100);
+
+        assert_eq!(meta.total_pages, 5);
+        assert!(!meta.has_next);
+        assert!(meta.has_prev);
+    }
+
+    #[test]
+    fn pagination_clamps_zero_page_and_page_size() {
+        let pagination = Pagination::new(0, 0);
+
+        assert_eq!(pagination, Pagination::new(1, 1));
+        assert_eq!(pagination.offset(), 0);
+        assert_eq!(pagination.limit(), 1);
+    }
+
+    #[test]
+    fn pagination_meta_for_zero_page_size_matches_clamped_request() {
+        let meta = PaginationMeta::new(1, 0, 3);
+
+        assert_eq!(meta.page_size, 1);
+        assert_eq!(meta.total_pages, 3);
+        assert!(meta.has_next);
+    }
+
+    #[test]
+    fn pagination_meta_empty_result_has_one_page() {
+        let meta = PaginationMeta::new(1, 20, 0);
+
+        assert_eq!(meta.total_pages, 1);
+        assert!(!meta.has_next);
+    }
+
+    #[test]
+    fn validate_uses_global_default() {
+        let params = PageParams::default().validate(100);
+
+        assert_eq!(params.page_size(), DEFAULT_PAGE_SIZE);
     }
 }
@@ -1,5 +1,5 @@
 use axum::Json;
-use axum::http::StatusCode;
+use axum::http::{HeaderValue, StatusCode, header};
 use axum::response::{IntoResponse, Response};
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +16,9 @@ pub struct SuccessResponse<T> {
     pub meta: Option<Meta>,
 }
 
+/// Media type of error responses, per RFC 7807
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
 /// Error response following RFC 7807 (Problem Details)
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -78,7 +81,7 @@ impl<T: Serialize> SuccessResponse<T> {
     }
 
     pub fn with_pagination(mut self, pagination: PaginationMeta) -> Self {
-        let meta = self.meta.get_or_insert_with(|| Meta {
+        let meta = self.meta.get_or_insert(Meta {
             pagination: None,
             timestamp: None,
         });
@@ -96,7 +99,16 @@ impl<T: Serialize> IntoResponse for SuccessResponse<T> {
 impl IntoResponse for ErrorResponse {
     fn into_response(self) -> Response {
         let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
-        (status, Json(self)).into_response()
+        // RFC 7807 media type; overrides the application/json set by Json
+        (
+            status,
+            [(
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE),
+            )],
+            Json(self),
+        )
+            .into_response()
     }
 }
 
@@ -116,6 +128,18 @@ pub fn created<T: Serialize>(data: T) -> impl IntoResponse {
     (StatusCode::CREATED, Json(SuccessResponse::new(data)))
 }
 
+/// 201 Created with a `Location` header pointing at the new resource
+pub fn created_at_location<T: Serialize>(data: T, location: impl Into<String>) -> Response {
+    let mut response = (StatusCode::CREATED, Json(SuccessResponse::new(data))).into_response();
+    match HeaderValue::try_from(location.into()) {
+        Ok(value) => {
+            response.headers_mut().insert(header::LOCATION, value);
+        }
+        Err(err) => tracing::warn!("Invalid Location header: {}", err),
+    }
+    response
+}
+
 pub fn accepted<T: Serialize>(data: T) -> impl IntoResponse {
     (StatusCode::ACCEPTED, Json(SuccessResponse::new(data)))
 }
@@ -0,0 +1,118 @@
+//! Sort order for list queries
+
+use std::fmt;
+
+/// Direction of an `ORDER BY` term
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        }
+    }
+}
+
+/// A column and direction to order a list by
+///
+/// `field` is always one of a repository's whitelisted columns, so it can be
+/// interpolated into SQL directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sort {
+    pub field: &'static str,
+    pub direction: SortDirection,
+}
+
+impl Sort {
+    pub const fn asc(field: &'static str) -> Self {
+        Self {
+            field,
+            direction: SortDirection::Asc,
+        }
+    }
+
+    pub const fn desc(field: &'static str) -> Self {
+        Self {
+            field,
+            direction: SortDirection::Desc,
+        }
+    }
+
+    /// Parse `field` or `field:asc|desc`, accepting only columns in `allowed`
+    ///
+    /// The direction defaults to ascending.
+    pub fn parse(value: &str, allowed: &[&'static str]) -> Option<Self> {
+        let (field, direction) = match value.trim().split_once(':') {
+            Some((field, direction)) => (field.trim(), direction.trim()),
+            None => (value.trim(), "asc"),
+        };
+        let field = *allowed.iter().find(|f| f.eq_ignore_ascii_case(field))?;
+
+        match direction.to_ascii_lowercase().as_str() {
+            "asc" => Some(Self::asc(field)),
+            "desc" => Some(Self::desc(field)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Sort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.field, self.direction.as_sql())
+    }
+}
+
+// =============================================================================
+// Unit Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALLOWED: &[&str] = &["created_at", "display_name"];
+
+    #[test]
+    fn parses_field_and_direction() {
+        assert_eq!(
+            Sort::parse("display_name:desc", ALLOWED),
+            Some(Sort::desc("display_name"))
+        );
+    }
+
+    #[test]
+    fn direction_defaults_to_ascending() {
+        assert_eq!(
+            Sort::parse("created_at", ALLOWED),
+            Some(Sort::asc("created_at"))
+        );
+    }
+
+    #[test]
+    fn is_case_insensitive() {
+        assert_eq!(
+            Sort::parse(" Display_Name : DESC ", ALLOWED),
+            Some(Sort::desc("display_name"))
+        );
+    }
+
+    #[test]
+    fn rejects_columns_outside_the_whitelist() {
+        assert_eq!(Sort::parse("id; DROP TABLE party", ALLOWED), None);
+    }
+
+    #[test]
+    fn rejects_unknown_direction() {
+        assert_eq!(Sort::parse("created_at:up", ALLOWED), None);
+    }
+
+    #[test]
+    fn displays_as_order_by_term() {
+        assert_eq!(Sort::desc("created_at").to_string(), "created_at DESC");
+    }
+}
@@ -0,0 +1,52 @@
+//! Helpers for building SQL fragments safely
+
+/// Escape character used by [`escape_like`]; queries must declare `ESCAPE '\'`
+pub const LIKE_ESCAPE: char = '\\';
+
+/// Escape `%`, `_` and `\` so `term` matches literally inside a LIKE/ILIKE pattern
+///
+/// The surrounding query must specify `ESCAPE '\'`.
+pub fn escape_like(term: &str) -> String {
+    let mut escaped = String::with_capacity(term.len());
+    for c in term.chars() {
+        if matches!(c, '%' | '_' | LIKE_ESCAPE) {
+            escaped.push(LIKE_ESCAPE);
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Build a `%term%` substring pattern with `term` escaped
+pub fn contains_pattern(term: &str) -> String {
+    format!("%{}%", escape_like(term))
+}
+
+// =============================================================================
+// Unit Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaves_plain_terms_untouched() {
+        assert_eq!(escape_like("Acme Corp"), "Acme Corp");
+    }
+
+    #[test]
+    fn escapes_wildcards_literally() {
+        assert_eq!(escape_like("50%_off"), r"50\%\_off");
+    }
+
+    #[test]
+    fn escapes_the_escape_character() {
+        assert_eq!(escape_like(r"C:\temp"), r"C:\\temp");
+    }
+
+    #[test]
+    fn contains_pattern_wraps_escaped_term() {
+        assert_eq!(contains_pattern("50%_off"), r"%50\%\_off%");
+    }
+}
@@ -0,0 +1,93 @@
+//! Client-supplied timestamps, normalized to UTC before they reach storage
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, de};
+
+use crate::error::DomainError;
+
+/// Parse an RFC 3339 timestamp with any offset and convert it to UTC
+///
+/// `2025-01-15T17:30:00+07:00` becomes `2025-01-15T10:30:00Z`. Values that are
+/// not RFC 3339, such as a bare date or a time without an offset, are rejected
+/// rather than guessed at. Wrap the error with
+/// `ValidationError::for_field` to report it against the request field.
+pub fn parse_utc(value: &str) -> Result<DateTime<Utc>, DomainError> {
+    DateTime::parse_from_rfc3339(value.trim())
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|_| {
+            DomainError::InvalidValue(format!(
+                "Invalid timestamp `{value}`: expected RFC 3339, e.g. 2025-01-15T10:30:00+07:00"
+            ))
+        })
+}
+
+/// `deserialize_with` helper applying [`parse_utc`] to a string field
+pub fn deserialize_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    parse_utc(&value).map_err(|err| de::Error::custom(err.message()))
+}
+
+// =============================================================================
+// Unit Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn normalizes_offset_to_utc() {
+        let parsed = parse_utc("2025-01-15T17:30:00+07:00").unwrap();
+
+        assert_eq!(
+            parsed,
+            Utc.with_ymd_and_hms(2025, 1, 15, 10, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn keeps_utc_input() {
+        let parsed = parse_utc("2025-01-15T10:30:00.250Z").unwrap();
+
+        assert_eq!(parsed.to_rfc3339(), "2025-01-15T10:30:00.250+00:00");
+    }
+
+    #[test]
+    fn rejects_non_rfc3339_values() {
+        for value in ["yesterday", "2025-01-15", "2025-01-15T10:30:00"] {
+            let err = parse_utc(value).unwrap_err();
+            assert!(matches!(err, DomainError::InvalidValue(_)), "{value}");
+        }
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct Import {
+        #[serde(deserialize_with = "deserialize_utc")]
+        created_at: DateTime<Utc>,
+    }
+
+    #[test]
+    fn deserializes_with_offset() {
+        let import: Import =
+            serde_json::from_str(r#"{ "created_at": "2025-01-15T03:30:00-07:00" }"#).unwrap();
+
+        assert_eq!(
+            import.created_at,
+            Utc.with_ymd_and_hms(2025, 1, 15, 10, 30, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn deserialize_reports_invalid_timestamp() {
+        let err = serde_json::from_str::<Import>(r#"{ "created_at": "not a time" }"#).unwrap_err();
+
+        assert!(
+            err.to_string()
+                .starts_with("Invalid timestamp `not a time`")
+        );
+    }
+}
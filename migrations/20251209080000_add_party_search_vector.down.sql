@@ -0,0 +1,3 @@
+-- Drop party search vector and its index
+DROP INDEX IF EXISTS idx_party_search_vector;
+ALTER TABLE party DROP COLUMN IF EXISTS search_vector;
@@ -0,0 +1,16 @@
+-- Add full-text search vector over party names and TIN
+ALTER TABLE party
+    ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+        to_tsvector(
+            'simple',
+            coalesce(display_name, '') || ' ' ||
+            coalesce(legal_name, '') || ' ' ||
+            coalesce(tin, '')
+        )
+    ) STORED;
+
+-- Create indexes
+CREATE INDEX idx_party_search_vector ON party USING GIN (search_vector);
+
+-- Add comments
+COMMENT ON COLUMN party.search_vector IS 'Full-text search vector (display_name, legal_name, tin)';
@@ -0,0 +1,3 @@
+-- Drop party soft-delete timestamp and its index
+DROP INDEX IF EXISTS idx_party_not_deleted;
+ALTER TABLE party DROP COLUMN IF EXISTS deleted_at;
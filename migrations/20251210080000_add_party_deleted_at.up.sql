@@ -0,0 +1,8 @@
+-- Add soft-delete timestamp, separate from the is_active business flag
+ALTER TABLE party ADD COLUMN deleted_at TIMESTAMPTZ;
+
+-- Create indexes
+CREATE INDEX idx_party_not_deleted ON party(created_at DESC) WHERE deleted_at IS NULL;
+
+-- Add comments
+COMMENT ON COLUMN party.deleted_at IS 'Soft-delete timestamp; NULL while the party exists';
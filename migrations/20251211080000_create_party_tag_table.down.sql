@@ -0,0 +1,2 @@
+-- Drop party_tag table
+DROP TABLE IF EXISTS party_tag;
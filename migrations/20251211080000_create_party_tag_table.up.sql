@@ -0,0 +1,16 @@
+-- Create party_tag table (free-form labels, stored lowercased)
+CREATE TABLE party_tag (
+    party_id    UUID NOT NULL REFERENCES party(id) ON DELETE CASCADE,
+    tag         TEXT NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (party_id, tag)
+);
+
+-- Create indexes
+CREATE INDEX idx_party_tag_tag ON party_tag(tag);
+
+-- Add comments
+COMMENT ON TABLE party_tag IS 'Ad-hoc labels attached to parties';
+COMMENT ON COLUMN party_tag.party_id IS 'Tagged party';
+COMMENT ON COLUMN party_tag.tag IS 'Lowercased label, unique per party';
+COMMENT ON COLUMN party_tag.created_at IS 'When the tag was added';
@@ -0,0 +1,4 @@
+-- Drop party trigram indexes (the extension is left installed)
+DROP INDEX IF EXISTS idx_party_tin_trgm;
+DROP INDEX IF EXISTS idx_party_legal_name_trgm;
+DROP INDEX IF EXISTS idx_party_display_name_trgm;
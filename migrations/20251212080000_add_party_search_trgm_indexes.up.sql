@@ -0,0 +1,7 @@
+-- Back substring search with trigram indexes
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+-- Create indexes
+CREATE INDEX idx_party_display_name_trgm ON party USING GIN (display_name gin_trgm_ops);
+CREATE INDEX idx_party_legal_name_trgm ON party USING GIN (legal_name gin_trgm_ops);
+CREATE INDEX idx_party_tin_trgm ON party USING GIN (tin gin_trgm_ops);